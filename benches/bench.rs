@@ -3,12 +3,20 @@ extern crate criterion;
 
 use criterion::{BenchmarkId, Criterion};
 use rust_decimal::prelude::FromPrimitive;
-use sfv::{BareItem, Decimal, Parser, SerializeValue};
+use sfv::{Decimal, Parser, ReusableParser, SerializeValue};
 use sfv::{RefBareItem, RefDictSerializer, RefItemSerializer, RefListSerializer};
 
-criterion_main!(parsing, serializing, ref_serializing);
+criterion_main!(parsing, validating, serializing, ref_serializing);
 
-criterion_group!(parsing, parsing_item, parsing_list, parsing_dict);
+criterion_group!(
+    parsing,
+    parsing_item,
+    parsing_list,
+    parsing_dict,
+    parsing_numbers,
+    parsing_strings,
+    parsing_many_fields
+);
 
 fn parsing_item(c: &mut Criterion) {
     let fixture =
@@ -44,6 +52,88 @@ fn parsing_dict(c: &mut Criterion) {
     );
 }
 
+fn parsing_numbers(c: &mut Criterion) {
+    let fixture = "1, 42, -7, 123456785686457, 99999999999.999, -0.5, 3.14, 1000, 0, 12.001";
+    c.bench_with_input(
+        BenchmarkId::new("parsing_numbers", fixture),
+        &fixture,
+        move |bench, &input| {
+            bench.iter(|| Parser::parse_list(input.as_bytes()).unwrap());
+        },
+    );
+}
+
+fn parsing_strings(c: &mut Criterion) {
+    let fixtures = [
+        "\"somelongstringvalue without any escaped characters\"",
+        "\"somelongstringvalue with \\\"escaped\\\" characters\"",
+    ];
+    for fixture in fixtures.iter() {
+        c.bench_with_input(
+            BenchmarkId::new("parsing_string", fixture),
+            fixture,
+            move |bench, &input| {
+                bench.iter(|| Parser::parse_bare_item(input.as_bytes()).unwrap());
+            },
+        );
+        c.bench_with_input(
+            BenchmarkId::new("parsing_bare_string", fixture),
+            fixture,
+            move |bench, &input| {
+                bench.iter(|| Parser::parse_bare_string(input.as_bytes()).unwrap());
+            },
+        );
+    }
+}
+
+fn parsing_many_fields(c: &mut Criterion) {
+    let fixtures = [
+        "\"somelongstringvalue\", \"anotherlongstringvalue\";key=tok",
+        "token-value, another-token-value;param=\"string parameter\"",
+        "(:c29tZXZlcnlsb25nc3RyaW5ndmFsdWU=: :YW5vdGhlcmxvbmdzdHJpbmd2YWx1ZQ==:);key=1",
+        "\"string with \\\"escaped\\\" characters\", 42, 1.5",
+    ];
+    c.bench_function("parsing_many_fields", |bench| {
+        bench.iter(|| {
+            for input in fixtures.iter() {
+                Parser::parse_list(input.as_bytes()).unwrap();
+            }
+        });
+    });
+    c.bench_function("parsing_many_fields_reused", |bench| {
+        let mut parser = ReusableParser::new();
+        bench.iter(|| {
+            for input in fixtures.iter() {
+                parser.parse_list(input.as_bytes()).unwrap();
+            }
+        });
+    });
+}
+
+criterion_group!(validating, validating_list, validating_dict);
+
+fn validating_list(c: &mut Criterion) {
+    let fixture = "a, abcdefghigklmnoprst, 123456785686457, 99999999999.999, (), (\"somelongstringvalue\" \"anotherlongstringvalue\";key=:c29tZXZlciBsb25nc3RyaW5ndmFsdWVyZXByZXNlbnRlZGFzYnl0ZXM: 145)";
+    c.bench_with_input(
+        BenchmarkId::new("validating_list", fixture),
+        &fixture,
+        move |bench, &input| {
+            bench.iter(|| Parser::validate_list(input.as_bytes()).unwrap());
+        },
+    );
+}
+
+fn validating_dict(c: &mut Criterion) {
+    let fixture = "a, dict_key2=abcdefghigklmnoprst, dict_key3=123456785686457, dict_key4=(\"inner-list-member\" :aW5uZXItbGlzdC1tZW1iZXI=:);key=aW5uZXItbGlzdC1wYXJhbWV0ZXJz";
+    c.bench_with_input(
+        BenchmarkId::new("validating_dict", fixture),
+        &fixture,
+        move |bench, &input| {
+            bench.iter(|| Parser::validate_dictionary(input.as_bytes()).unwrap());
+        },
+    );
+}
+
 criterion_group!(
     serializing,
     serializing_item,
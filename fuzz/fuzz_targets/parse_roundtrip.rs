@@ -0,0 +1,36 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use sfv::{Parser, SerializeValue};
+
+// Parses arbitrary input as each structured field type and, if parsing succeeds,
+// checks that serialized value parses back into the same structure
+// and that serialization of the re-parsed value is idempotent.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(item) = Parser::parse_item(data) {
+        let serialized = item.serialize_value().expect("parsed item must serialize");
+        let reparsed =
+            Parser::parse_item(serialized.as_bytes()).expect("serialized item must parse");
+        assert_eq!(item, reparsed);
+        assert_eq!(serialized, reparsed.serialize_value().unwrap());
+    }
+
+    if let Ok(list) = Parser::parse_list(data) {
+        // Empty List is valid to parse, but not to serialize
+        if let Ok(serialized) = list.serialize_value() {
+            let reparsed =
+                Parser::parse_list(serialized.as_bytes()).expect("serialized list must parse");
+            assert_eq!(list, reparsed);
+            assert_eq!(serialized, reparsed.serialize_value().unwrap());
+        }
+    }
+
+    if let Ok(dict) = Parser::parse_dictionary(data) {
+        // Empty Dictionary is valid to parse, but not to serialize
+        if let Ok(serialized) = dict.serialize_value() {
+            let reparsed = Parser::parse_dictionary(serialized.as_bytes())
+                .expect("serialized dictionary must parse");
+            assert_eq!(dict, reparsed);
+            assert_eq!(serialized, reparsed.serialize_value().unwrap());
+        }
+    }
+});
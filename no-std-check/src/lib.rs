@@ -0,0 +1,18 @@
+//! Compile-only check that `sfv` builds without `std`.
+//! Build it for a target without the standard library, e.g. `cargo build --target thumbv7m-none-eabi`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use sfv::{BareItem, Dictionary, Item, ListEntry, Parser, SerializeValue};
+
+pub fn roundtrip(input: &[u8]) -> Option<String> {
+    let mut dict: Dictionary = Parser::parse_dictionary(input).ok()?;
+    dict.insert(
+        String::from("checked"),
+        ListEntry::Item(Item::new(BareItem::Boolean(true))),
+    );
+    dict.serialize_value().ok()
+}
@@ -0,0 +1,329 @@
+/*!
+Conversion of JSON values into structured field values. Requires `json` feature.
+
+Conversion rules:
+- JSON number without fractional part becomes `BareItem::Integer`, otherwise `BareItem::Decimal`.
+- JSON string becomes `BareItem::String`.
+- JSON boolean becomes `BareItem::Boolean`.
+- JSON array becomes `List` when converted as a field value, or `InnerList` when nested as a member.
+- JSON object becomes `Dictionary`, its keys must be valid structured field keys.
+
+JSON `null`, objects nested as members and arrays nested deeper than an inner list have no structured field analog.
+Integers out of `[-999_999_999_999_999, 999_999_999_999_999]` range,
+decimals with more than 12 digits in integer component and strings with non-ASCII or control characters can't be represented either.
+All of those result in an error.
+
+# Examples
+```
+use serde_json::json;
+use sfv::{from_json, SerializeValue};
+
+let value = json!({"a": 1, "b": [2.5, "foo"], "c": true});
+let dict = from_json::dictionary(&value).unwrap();
+assert_eq!(dict.serialize_value().unwrap(), "a=1, b=(2.5 \"foo\"), c");
+```
+*/
+
+use crate::serializer::Serializer;
+use crate::{
+    BareItem, Decimal, Dictionary, FromPrimitive, InnerList, Item, List, ListEntry, SFVResult,
+    MAX_INTEGER,
+};
+use core::convert::TryFrom;
+use serde_json::{Map, Number, Value};
+
+/// Converts JSON scalar into `BareItem`.
+pub fn bare_item(value: &Value) -> SFVResult<BareItem> {
+    match value {
+        Value::Number(number) => number_to_bare_item(number),
+        Value::String(string) => {
+            Serializer::validate_string(string)
+                .map_err(|_| "from_json: string contains non-ascii or control characters")?;
+            Ok(BareItem::String(string.clone()))
+        }
+        Value::Bool(boolean) => Ok(BareItem::Boolean(*boolean)),
+        Value::Null => Err("from_json: null can't be represented as bare item"),
+        Value::Array(_) | Value::Object(_) => {
+            Err("from_json: array or object can't be represented as bare item")
+        }
+    }
+}
+
+impl TryFrom<&Value> for BareItem {
+    type Error = &'static str;
+
+    /// Converts JSON scalar into `BareItem`, same as `from_json::bare_item`.
+    fn try_from(value: &Value) -> SFVResult<BareItem> {
+        bare_item(value)
+    }
+}
+
+impl TryFrom<Value> for BareItem {
+    type Error = &'static str;
+
+    /// Converts JSON scalar into `BareItem`, same as `from_json::bare_item`,
+    /// but moves JSON string instead of copying it.
+    /// ```
+    /// # use serde_json::json;
+    /// # use sfv::BareItem;
+    /// # use std::convert::TryFrom;
+    /// assert_eq!(BareItem::try_from(json!(true)), Ok(BareItem::Boolean(true)));
+    /// assert!(BareItem::try_from(json!(null)).is_err());
+    /// ```
+    fn try_from(value: Value) -> SFVResult<BareItem> {
+        match value {
+            Value::String(string) => {
+                Serializer::validate_string(&string)
+                    .map_err(|_| "from_json: string contains non-ascii or control characters")?;
+                Ok(BareItem::String(string))
+            }
+            value => bare_item(&value),
+        }
+    }
+}
+
+/// Converts JSON scalar into `Item` with empty `Parameters`.
+pub fn item(value: &Value) -> SFVResult<Item> {
+    Ok(Item::new(bare_item(value)?))
+}
+
+/// Converts JSON array into `List`.
+/// Scalar array elements become `Items`, nested arrays become `InnerLists`.
+pub fn list(value: &Value) -> SFVResult<List> {
+    match value {
+        Value::Array(values) => values.iter().map(list_entry).collect(),
+        _ => Err("from_json: list must be converted from array"),
+    }
+}
+
+/// Converts JSON object into `Dictionary`.
+/// Scalar object values become `Items`, array values become `InnerLists`.
+pub fn dictionary(value: &Value) -> SFVResult<Dictionary> {
+    match value {
+        Value::Object(members) => object_to_dictionary(members),
+        _ => Err("from_json: dictionary must be converted from object"),
+    }
+}
+
+fn object_to_dictionary(members: &Map<String, Value>) -> SFVResult<Dictionary> {
+    let mut dict = Dictionary::new();
+    for (key, value) in members {
+        Serializer::validate_key(key).map_err(|_| "from_json: invalid dictionary key")?;
+        dict.insert(key.clone(), list_entry(value)?);
+    }
+    Ok(dict)
+}
+
+fn list_entry(value: &Value) -> SFVResult<ListEntry> {
+    match value {
+        Value::Array(values) => {
+            let items = values.iter().map(item).collect::<SFVResult<Vec<Item>>>()?;
+            Ok(InnerList::new(items).into())
+        }
+        Value::Object(_) => Err("from_json: object can't be a member of list or dictionary"),
+        _ => Ok(item(value)?.into()),
+    }
+}
+
+fn number_to_bare_item(number: &Number) -> SFVResult<BareItem> {
+    if let Some(int) = number.as_i64() {
+        Serializer::validate_integer(int).map_err(|_| "from_json: integer is out of range")?;
+        return Ok(BareItem::Integer(int));
+    }
+
+    if number.is_u64() {
+        return Err("from_json: integer is out of range");
+    }
+
+    let float = number
+        .as_f64()
+        .ok_or("from_json: number can't be represented as decimal")?;
+    if float.fract() == 0.0 && float.abs() <= MAX_INTEGER as f64 {
+        return Ok(BareItem::Integer(float as i64));
+    }
+
+    let decimal =
+        Decimal::from_f64(float).ok_or("from_json: number can't be represented as decimal")?;
+    Serializer::validate_decimal(decimal)
+        .map_err(|_| "from_json: decimal integer component > 12 digits")?;
+    Ok(BareItem::Decimal(decimal))
+}
+
+#[cfg(test)]
+mod from_json_tests {
+    use super::*;
+    use crate::{FromStr, Parameters, ParametersBuilder};
+    use serde_json::json;
+    use std::error::Error;
+    use std::iter::FromIterator;
+
+    #[test]
+    fn convert_bare_item() -> Result<(), Box<dyn Error>> {
+        assert_eq!(BareItem::Integer(42), bare_item(&json!(42))?);
+        assert_eq!(BareItem::Integer(-42), bare_item(&json!(-42))?);
+        assert_eq!(BareItem::Integer(3), bare_item(&json!(3.0))?);
+        assert_eq!(
+            BareItem::Decimal(Decimal::from_str("2.5")?),
+            bare_item(&json!(2.5))?
+        );
+        assert_eq!(
+            BareItem::String("foo".to_owned()),
+            bare_item(&json!("foo"))?
+        );
+        assert_eq!(BareItem::Boolean(false), bare_item(&json!(false))?);
+        Ok(())
+    }
+
+    #[test]
+    fn convert_bare_item_errors() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Err("from_json: null can't be represented as bare item"),
+            bare_item(&json!(null))
+        );
+        assert_eq!(
+            Err("from_json: array or object can't be represented as bare item"),
+            bare_item(&json!([1]))
+        );
+        assert_eq!(
+            Err("from_json: integer is out of range"),
+            bare_item(&json!(1_000_000_000_000_000_i64))
+        );
+        assert_eq!(
+            Err("from_json: integer is out of range"),
+            bare_item(&json!(u64::MAX))
+        );
+        assert_eq!(
+            Err("from_json: decimal integer component > 12 digits"),
+            bare_item(&json!(1_000_000_000_000.5))
+        );
+        assert_eq!(
+            Err("from_json: string contains non-ascii or control characters"),
+            bare_item(&json!("non-ascii 🐹"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_bare_item_try_from() -> Result<(), Box<dyn Error>> {
+        assert_eq!(BareItem::Integer(7), BareItem::try_from(json!(7))?);
+        assert_eq!(
+            BareItem::Decimal(Decimal::from_str("-0.25")?),
+            BareItem::try_from(json!(-0.25))?
+        );
+        assert_eq!(BareItem::Boolean(true), BareItem::try_from(json!(true))?);
+        assert_eq!(
+            BareItem::String("foo".to_owned()),
+            BareItem::try_from(json!("foo"))?
+        );
+        assert_eq!(
+            BareItem::String("bar".to_owned()),
+            BareItem::try_from(&json!("bar"))?
+        );
+
+        assert_eq!(
+            Err("from_json: null can't be represented as bare item"),
+            BareItem::try_from(json!(null))
+        );
+        assert_eq!(
+            Err("from_json: array or object can't be represented as bare item"),
+            BareItem::try_from(json!({"a": 1}))
+        );
+        assert_eq!(
+            Err("from_json: array or object can't be represented as bare item"),
+            BareItem::try_from(&json!([]))
+        );
+        assert_eq!(
+            Err("from_json: string contains non-ascii or control characters"),
+            BareItem::try_from(json!("tab\t"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_item() -> Result<(), Box<dyn Error>> {
+        assert_eq!(Item::new(BareItem::Integer(1)), item(&json!(1))?);
+        assert_eq!(
+            Err("from_json: array or object can't be represented as bare item"),
+            item(&json!({"a": 1}))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn insert_json_into_parameters_builder() -> Result<(), Box<dyn Error>> {
+        let mut builder = ParametersBuilder::new();
+        builder.insert("a", json!(1))?.insert("b", json!("str"))?;
+        assert_eq!(
+            Err("parameters_builder: value can't be converted into bare item"),
+            builder.insert("c", json!(null)).map(|_| ())
+        );
+        let params = builder.build();
+        assert_eq!(Some(&BareItem::Integer(1)), params.get("a"));
+        assert_eq!(Some(&BareItem::String("str".to_owned())), params.get("b"));
+        assert_eq!(None, params.get("c"));
+        Ok(())
+    }
+
+    #[test]
+    fn convert_list() -> Result<(), Box<dyn Error>> {
+        let expected: List = vec![
+            Item::new(BareItem::Integer(1)).into(),
+            InnerList::new(vec![
+                Item::new(BareItem::String("a".to_owned())),
+                Item::new(BareItem::Boolean(true)),
+            ])
+            .into(),
+        ];
+        assert_eq!(expected, list(&json!([1, ["a", true]]))?);
+        assert_eq!(List::new(), list(&json!([]))?);
+        Ok(())
+    }
+
+    #[test]
+    fn convert_list_errors() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Err("from_json: list must be converted from array"),
+            list(&json!(1))
+        );
+        assert_eq!(
+            Err("from_json: object can't be a member of list or dictionary"),
+            list(&json!([{"a": 1}]))
+        );
+        assert_eq!(
+            Err("from_json: array or object can't be represented as bare item"),
+            list(&json!([[[1]]]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_dictionary() -> Result<(), Box<dyn Error>> {
+        let expected = Dictionary::from_iter(vec![
+            ("a".to_owned(), Item::new(BareItem::Integer(1)).into()),
+            (
+                "b".to_owned(),
+                InnerList::with_params(vec![Item::new(BareItem::Integer(2))], Parameters::new())
+                    .into(),
+            ),
+        ]);
+        assert_eq!(expected, dictionary(&json!({"a": 1, "b": [2]}))?);
+        Ok(())
+    }
+
+    #[test]
+    fn convert_dictionary_errors() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Err("from_json: dictionary must be converted from object"),
+            dictionary(&json!([1]))
+        );
+        assert_eq!(
+            Err("from_json: invalid dictionary key"),
+            dictionary(&json!({"Key": 1}))
+        );
+        assert_eq!(
+            Err("from_json: object can't be a member of list or dictionary"),
+            dictionary(&json!({"a": {"b": 1}}))
+        );
+        Ok(())
+    }
+}
@@ -16,6 +16,15 @@ There's also a few primitive types used to construct structured field values:
 - `InnerList` is an array of zero or more `Items`. Can have `Parameters`.
 - `ListEntry` represents either `Item` or `InnerList` as a member of `List` or as member-value in `Dictionary`.
 
+# Crate Features
+
+- `std` (enabled by default) - links the standard library. When disabled, the crate is `no_std` and only requires `alloc`.
+  `Dictionary` and `Parameters` then use a fixed-key FNV hasher instead of `RandomState`, see `HashBuilder`.
+  Since the hash of a key is predictable, input crafted to contain many colliding dictionary or parameter keys
+  makes parsing quadratic in the number of keys (hash flooding). Limit the size of untrusted input
+  before parsing it when building without `std`.
+- `json` - enables `from_json` module for converting `serde_json::Value` into structured field values. Implies `std`.
+
 # Examples
 
 ### Parsing
@@ -109,7 +118,7 @@ Creates `Item` field value with parameters:
 ```
 use sfv::{Item, BareItem, SerializeValue, Parameters, Decimal, FromPrimitive};
 
-let mut params = Parameters::new();
+let mut params = Parameters::default();
 let decimal = Decimal::from_f64(13.45655).unwrap();
 params.insert("key".into(), BareItem::Decimal(decimal));
 let int_item = Item::with_params(BareItem::Integer(99), params);
@@ -126,12 +135,12 @@ let tok_item = BareItem::Token("tok".into());
 let str_item = Item::new(BareItem::String(String::from("foo")));
 
 // Creates InnerList members.
-let mut int_item_params = Parameters::new();
+let mut int_item_params = Parameters::default();
 int_item_params.insert("key".into(), BareItem::Boolean(false));
 let int_item = Item::with_params(BareItem::Integer(99), int_item_params);
 
 // Creates InnerList.
-let mut inner_list_params = Parameters::new();
+let mut inner_list_params = Parameters::default();
 inner_list_params.insert("bar".into(), BareItem::Boolean(true));
 let inner_list = InnerList::with_params(vec![int_item, str_item], inner_list_params);
 
@@ -151,7 +160,7 @@ let member_value1 = Item::new(BareItem::String(String::from("apple")));
 let member_value2 = Item::new(BareItem::Boolean(true));
 let member_value3 = Item::new(BareItem::Boolean(false));
 
-let mut dict = Dictionary::new();
+let mut dict = Dictionary::default();
 dict.insert("key1".into(), member_value1.into());
 dict.insert("key2".into(), member_value2.into());
 dict.insert("key3".into(), member_value3.into());
@@ -164,15 +173,29 @@ assert_eq!(
 ```
 */
 
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "json")]
+pub mod from_json;
+mod macros;
 mod parser;
+pub mod qvalue;
 mod ref_serializer;
 mod serializer;
+pub mod text;
 mod utils;
 
 #[cfg(test)]
 mod test_parser;
 #[cfg(test)]
 mod test_serializer;
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
+use core::hash::{Hash, Hasher};
+use core::mem;
 use indexmap::IndexMap;
 
 pub use rust_decimal::{
@@ -180,18 +203,102 @@ pub use rust_decimal::{
     Decimal,
 };
 
-pub use parser::{ParseMore, ParseValue, Parser};
-pub use ref_serializer::{RefDictSerializer, RefItemSerializer, RefListSerializer};
-pub use serializer::SerializeValue;
+pub use parser::{
+    Canonicality, DictionaryVisitor, ParseMore, ParseValue, Parser, ParserConfig, ReusableParser,
+    VisitFlow,
+};
+pub use ref_serializer::{
+    serialize_dict_from_iter, RefDictSerializer, RefItemSerializer, RefListSerializer,
+};
+pub use serializer::{
+    append_list_member, classify_str, locate_invalid_chars, quote_string, serialize_list_from_iter,
+    InvalidChars, SerializeOptions, SerializePretty, SerializeValue, StrClassification,
+};
+#[cfg(not(feature = "std"))]
+pub use utils::FnvHasher;
+
+type SFVResult<T> = core::result::Result<T, &'static str>;
+
+/// Largest value of `BareItem::Integer` allowed by RFC 8941, i.e. 15 decimal digits.
+pub const MAX_INTEGER: i64 = 999_999_999_999_999;
+
+/// Smallest value of `BareItem::Integer` allowed by RFC 8941, i.e. 15 decimal digits with a minus sign.
+pub const MIN_INTEGER: i64 = -999_999_999_999_999;
+
+// Items used by exported macros, not a part of public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{dict, item, list, validate_token};
+    pub use alloc::string::String;
+    pub use alloc::vec;
+}
+
+/// Hash builder used by `Dictionary` and `Parameters`.
+/// Without `std` feature there's no source of randomness, so a fixed-key FNV hasher is used instead.
+#[cfg(feature = "std")]
+pub type HashBuilder = std::collections::hash_map::RandomState;
+
+/// Hash builder used by `Dictionary` and `Parameters`.
+/// Without `std` feature there's no source of randomness, so a fixed-key FNV hasher is used instead.
+///
+/// # Hash flooding
+/// `FnvHasher` is not keyed, so anyone can compute keys whose hashes collide.
+/// Parsing untrusted input with many such keys degrades `Dictionary` and `Parameters` lookups
+/// to linear time, and parsing a field to quadratic time, which can be used for denial of service.
+/// Bound the size of untrusted input before parsing it.
+#[cfg(not(feature = "std"))]
+pub type HashBuilder = core::hash::BuildHasherDefault<utils::FnvHasher>;
 
-type SFVResult<T> = std::result::Result<T, &'static str>;
+/// Implemented by structured field value types, i.e. `Item`, `List` and `Dictionary`,
+/// for code which handles fields regardless of their type.
+/// ```
+/// # use sfv::{Parser, StructuredField};
+/// fn describe(field: &impl StructuredField) -> String {
+///     match field.serialize_value() {
+///         Ok(value) => format!("{} member(s): {}", field.member_count(), value),
+///         Err(_) => String::from("invalid field"),
+///     }
+/// }
+///
+/// let list = Parser::parse_list("a, (b c)".as_bytes()).unwrap();
+/// assert_eq!(describe(&list), "2 member(s): a, (b c)");
+/// let item = Parser::parse_item("a;b".as_bytes()).unwrap();
+/// assert_eq!(describe(&item), "1 member(s): a;b");
+/// ```
+pub trait StructuredField: SerializeValue {
+    /// Returns the number of top-level members, which is always 1 for an `Item`.
+    fn member_count(&self) -> usize;
+
+    /// Returns `true` if there are no members. Empty fields can't be serialized.
+    fn is_empty(&self) -> bool {
+        self.member_count() == 0
+    }
+}
+
+impl StructuredField for Item {
+    fn member_count(&self) -> usize {
+        1
+    }
+}
+
+impl StructuredField for List {
+    fn member_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl StructuredField for Dictionary {
+    fn member_count(&self) -> usize {
+        self.len()
+    }
+}
 
 /// Represents `Item` type structured field value.
 /// Can be used as a member of `List` or `Dictionary`.
 // sf-item   = bare-item parameters
 // bare-item = sf-integer / sf-decimal / sf-string / sf-token
 //             / sf-binary / sf-boolean
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Item {
     /// Value of `Item`.
     pub bare_item: BareItem,
@@ -204,13 +311,198 @@ impl Item {
     pub fn new(bare_item: BareItem) -> Item {
         Item {
             bare_item,
-            params: Parameters::new(),
+            params: Parameters::default(),
         }
     }
     /// Returns new `Item` with specified `Parameters`.
     pub fn with_params(bare_item: BareItem, params: Parameters) -> Item {
         Item { bare_item, params }
     }
+    /// Returns `Item` with `BareItem` transformed by `f`. `Parameters` are preserved.
+    /// ```
+    /// # use sfv::{BareItem, Item, Parser, SerializeValue};
+    /// let item = Parser::parse_item("10;unit=ms".as_bytes()).unwrap();
+    /// let item = item.map_bare_item(|bare_item| match bare_item {
+    ///     BareItem::Integer(val) => BareItem::Integer(val * 1000),
+    ///     other => other,
+    /// });
+    /// assert_eq!(item.serialize_value().unwrap(), "10000;unit=ms");
+    /// ```
+    pub fn map_bare_item(self, f: impl FnOnce(BareItem) -> BareItem) -> Item {
+        Item {
+            bare_item: f(self.bare_item),
+            params: self.params,
+        }
+    }
+    /// Returns `Item` with `Parameters` transformed by `f`. `BareItem` is preserved.
+    /// ```
+    /// # use sfv::{Item, Parser, SerializeValue};
+    /// let item = Parser::parse_item("10;unit=ms;internal".as_bytes()).unwrap();
+    /// let item = item.map_params(|mut params| {
+    ///     params.shift_remove("internal");
+    ///     params
+    /// });
+    /// assert_eq!(item.serialize_value().unwrap(), "10;unit=ms");
+    /// ```
+    pub fn map_params(self, f: impl FnOnce(Parameters) -> Parameters) -> Item {
+        Item {
+            bare_item: self.bare_item,
+            params: f(self.params),
+        }
+    }
+
+    /// Inserts parameters from `params` into `Item`'s `Parameters`, validating all keys and values first.
+    /// If any parameter is invalid, returns its key along with the error and leaves `Parameters` unchanged.
+    /// ```
+    /// # use sfv::{BareItem, Item, SerializeValue};
+    /// let mut item = Item::new(1.into());
+    /// item.extend_params(vec![("a".to_owned(), BareItem::Boolean(true)), ("b".to_owned(), 2.into())]).unwrap();
+    /// assert_eq!(item.serialize_value().unwrap(), "1;a;b=2");
+    ///
+    /// let result = item.extend_params(vec![("c".to_owned(), 3.into()), ("D".to_owned(), 4.into())]);
+    /// assert_eq!(result, Err(("D".to_owned(), "serialize_key: disallowed character in input")));
+    /// assert_eq!(item.serialize_value().unwrap(), "1;a;b=2");
+    /// ```
+    pub fn extend_params<I: IntoIterator<Item = (String, BareItem)>>(
+        &mut self,
+        params: I,
+    ) -> Result<(), (String, &'static str)> {
+        extend_params(&mut self.params, params)
+    }
+
+    /// Sorts `Parameters` by key, so that items can be compared or hashed
+    /// regardless of the order their parameters were specified in.
+    ///
+    /// Note that parameters order is significant in RFC 8941 and this changes serialized output.
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let mut item = Parser::parse_item("1;b=2;a;c=3".as_bytes()).unwrap();
+    /// item.sort_params();
+    /// assert_eq!(item.serialize_value().unwrap(), "1;a;b=2;c=3");
+    /// ```
+    pub fn sort_params(&mut self) {
+        self.params.sort_keys();
+    }
+
+    /// Removes all `Parameters`, keeping only `BareItem`, e.g. to compare items by their values.
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let mut item = Parser::parse_item("1;a;b=2".as_bytes()).unwrap();
+    /// item.strip_params();
+    /// assert_eq!(item.serialize_value().unwrap(), "1");
+    /// ```
+    pub fn strip_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub(crate) fn compact(&mut self) {
+        self.params.shrink_to_fit();
+    }
+}
+
+impl Hash for Item {
+    /// Hashes `BareItem` and `Parameters`.
+    /// `Parameters` are equal regardless of their order, so they are hashed regardless of their order too.
+    /// ```
+    /// # use sfv::Parser;
+    /// # use std::collections::HashSet;
+    /// let mut items = HashSet::new();
+    /// items.insert(Parser::parse_item("1;a;b=2".as_bytes()).unwrap());
+    /// items.insert(Parser::parse_item("1;b=2;a".as_bytes()).unwrap());
+    /// items.insert(Parser::parse_item("1.0".as_bytes()).unwrap());
+    /// items.insert(Parser::parse_item("1".as_bytes()).unwrap());
+    /// assert_eq!(items.len(), 3);
+    /// assert!(items.contains(&Parser::parse_item("1;b=2;a".as_bytes()).unwrap()));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bare_item.hash(state);
+        hash_params(&self.params, state);
+    }
+}
+
+// New parameters are validated before inserting any of them, so that invalid input leaves params unchanged
+fn extend_params<I: IntoIterator<Item = (String, BareItem)>>(
+    params: &mut Parameters,
+    new_params: I,
+) -> Result<(), (String, &'static str)> {
+    let new_params: Vec<(String, BareItem)> = new_params.into_iter().collect();
+    for (key, value) in new_params.iter() {
+        serializer::Serializer::validate_key(key)
+            .and_then(|_| serializer::Serializer::validate_bare_item(value))
+            .map_err(|err| (key.clone(), err))?;
+    }
+    params.extend(new_params);
+    Ok(())
+}
+
+// Parameters are equal regardless of their order, so each parameter is hashed on its own
+// and the hashes are combined with an order-independent sum.
+// The per-parameter hasher must not be randomly keyed, so that equal parameters always hash equally
+fn hash_params<H: Hasher>(params: &Parameters, state: &mut H) {
+    #[cfg(feature = "std")]
+    type ParamHasher = std::collections::hash_map::DefaultHasher;
+    #[cfg(not(feature = "std"))]
+    type ParamHasher = utils::FnvHasher;
+
+    let combined = params.iter().fold(0_u64, |acc, param| {
+        let mut hasher = ParamHasher::default();
+        param.hash(&mut hasher);
+        acc.wrapping_add(hasher.finish())
+    });
+    state.write_usize(params.len());
+    state.write_u64(combined);
+}
+
+impl PartialEq<BareItem> for Item {
+    /// Compares `Item` to a `BareItem`. They are equal only if `Item` has no `Parameters`,
+    /// since `Item` with parameters carries more information than the `BareItem` alone.
+    /// To compare values regardless of parameters, compare `Item::bare_item` directly.
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let item = Parser::parse_item("5".as_bytes()).unwrap();
+    /// assert!(item == BareItem::Integer(5));
+    ///
+    /// let item = Parser::parse_item("5;a".as_bytes()).unwrap();
+    /// assert!(item != BareItem::Integer(5));
+    /// assert!(item.bare_item == BareItem::Integer(5));
+    /// ```
+    fn eq(&self, other: &BareItem) -> bool {
+        self.params.is_empty() && &self.bare_item == other
+    }
+}
+
+impl TryFrom<&[u8]> for Item {
+    type Error = &'static str;
+
+    /// Parses input into `Item`, same as `Parser::parse_item`.
+    /// `List` and `Dictionary` are type aliases, so those are parsed with `Parser` only.
+    /// ```
+    /// # use sfv::{BareItem, Item};
+    /// # use std::convert::TryFrom;
+    /// let item = Item::try_from("12;a".as_bytes()).unwrap();
+    /// assert_eq!(item.bare_item, BareItem::Integer(12));
+    /// ```
+    fn try_from(input_bytes: &[u8]) -> SFVResult<Item> {
+        Parser::parse_item(input_bytes)
+    }
+}
+
+impl TryFrom<&str> for Item {
+    type Error = &'static str;
+
+    /// Parses input into `Item`, same as `Parser::parse_item`.
+    fn try_from(input: &str) -> SFVResult<Item> {
+        Parser::parse_item(input.as_bytes())
+    }
+}
+
+impl TryFrom<String> for Item {
+    type Error = &'static str;
+
+    /// Parses input into `Item`, same as `Parser::parse_item`.
+    fn try_from(input: String) -> SFVResult<Item> {
+        Parser::parse_item(input.as_bytes())
+    }
 }
 
 /// Represents `Dictionary` type structured field value.
@@ -218,13 +510,347 @@ impl Item {
 // dict-member    = member-name [ "=" member-value ]
 // member-name    = key
 // member-value   = sf-item / inner-list
-pub type Dictionary = IndexMap<String, ListEntry>;
+pub type Dictionary = IndexMap<String, ListEntry, HashBuilder>;
+
+/// Extends `Dictionary` with typed access to its members.
+pub trait DictionaryExt {
+    /// Returns `Item` associated with `key`.
+    /// Returns `None` if `key` is not present or its value is an `InnerList`.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1;x, b=(2 3)".as_bytes()).unwrap();
+    /// assert_eq!(dict.get_item("a").unwrap().bare_item.as_int(), Some(1));
+    /// assert!(dict.get_item("b").is_none());
+    /// assert!(dict.get_item("c").is_none());
+    /// ```
+    fn get_item(&self, key: &str) -> Option<&Item>;
+
+    /// Returns `InnerList` associated with `key`.
+    /// Returns `None` if `key` is not present or its value is an `Item`.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1;x, b=(2 3)".as_bytes()).unwrap();
+    /// assert_eq!(dict.get_inner_list("b").unwrap().items.len(), 2);
+    /// assert!(dict.get_inner_list("a").is_none());
+    /// assert!(dict.get_inner_list("c").is_none());
+    /// ```
+    fn get_inner_list(&self, key: &str) -> Option<&InnerList>;
+
+    /// Returns `Item` associated with `key`,
+    /// telling apart a missing `key` from `key` associated with an `InnerList`.
+    /// ```
+    /// # use sfv::{BareItem, DictMember, DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1;x, b=(2 3)".as_bytes()).unwrap();
+    /// match dict.get_typed_item("a") {
+    ///     DictMember::Present(item) => assert_eq!(item.bare_item, BareItem::Integer(1)),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(dict.get_typed_item("b"), DictMember::WrongKind);
+    /// assert_eq!(dict.get_typed_item("c"), DictMember::Absent);
+    /// ```
+    fn get_typed_item(&self, key: &str) -> DictMember<'_, Item>;
+
+    /// Returns `InnerList` associated with `key`,
+    /// telling apart a missing `key` from `key` associated with an `Item`.
+    /// ```
+    /// # use sfv::{DictMember, DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1;x, b=(2 3)".as_bytes()).unwrap();
+    /// assert_eq!(dict.get_typed_inner_list("b").present().unwrap().items.len(), 2);
+    /// assert_eq!(dict.get_typed_inner_list("a"), DictMember::WrongKind);
+    /// assert_eq!(dict.get_typed_inner_list("c"), DictMember::Absent);
+    /// ```
+    fn get_typed_inner_list(&self, key: &str) -> DictMember<'_, InnerList>;
+
+    /// Iterates over `Dictionary` members which values are `Items`, skipping `InnerLists`.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1, b=(2 3), c, d=(), e=?0".as_bytes()).unwrap();
+    /// let keys: Vec<&str> = dict.iter_items().map(|(key, _)| key.as_str()).collect();
+    /// assert_eq!(keys, vec!["a", "c", "e"]);
+    /// ```
+    fn iter_items(&self) -> impl Iterator<Item = (&String, &Item)>;
+
+    /// Iterates over `Dictionary` members which values are `InnerLists`, skipping `Items`.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1, b=(2 3), c, d=(), e=?0".as_bytes()).unwrap();
+    /// let keys: Vec<&str> = dict.iter_inner_lists().map(|(key, _)| key.as_str()).collect();
+    /// assert_eq!(keys, vec!["b", "d"]);
+    /// ```
+    fn iter_inner_lists(&self) -> impl Iterator<Item = (&String, &InnerList)>;
+
+    /// Builds `Dictionary` from key-value pairs, validating keys.
+    /// If a key occurs more than once, its last value is kept at the position of its first occurrence.
+    /// ```
+    /// # use sfv::{BareItem, Dictionary, DictionaryExt, InnerList, Item, SerializeValue};
+    /// let dict = Dictionary::from_pairs(vec![
+    ///     ("a", Item::new(1.into())),
+    ///     ("b", Item::new(BareItem::Token("tok".into()))),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(dict.serialize_value().unwrap(), "a=1, b=tok");
+    ///
+    /// let dict = Dictionary::from_pairs(vec![("Key", InnerList::new(vec![]))]);
+    /// assert_eq!(dict, Err("serialize_key: disallowed character in input"));
+    /// ```
+    fn from_pairs<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> SFVResult<Self>
+    where
+        Self: Sized,
+        K: Into<String>,
+        V: Into<ListEntry>;
+
+    /// Shrinks capacity of the `Dictionary`, its inner lists and parameters as much as possible.
+    /// Useful for parsed values which are kept for a long time.
+    fn compact(&mut self);
+
+    /// Keeps only members for which `keep` returns `true`, preserving their order.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser, SerializeValue};
+    /// let mut dict = Parser::parse_dictionary("a=1, x-internal=2, b=(3)".as_bytes()).unwrap();
+    /// dict.retain_entries(|key, _| !key.starts_with("x-"));
+    /// assert_eq!(dict.serialize_value().unwrap(), "a=1, b=(3)");
+    /// ```
+    fn retain_entries(&mut self, keep: impl FnMut(&str, &ListEntry) -> bool);
+
+    /// Sets parameter `param` of member `key` to `value`, validating both keys and the value.
+    /// If `key` is not present, inserts it as a `Boolean(true)` `Item`, i.e. a member without value.
+    /// If member is an `InnerList`, parameter is set on the `InnerList` itself.
+    /// ```
+    /// # use sfv::{BareItem, Dictionary, DictionaryExt, Parser, SerializeValue};
+    /// let mut dict = Parser::parse_dictionary("a=1;x=2".as_bytes()).unwrap();
+    /// dict.upsert_param("a", "x", BareItem::Integer(3)).unwrap();
+    /// dict.upsert_param("b", "y", BareItem::Token("tok".into())).unwrap();
+    /// assert_eq!(dict.serialize_value().unwrap(), "a=1;x=3, b;y=tok");
+    ///
+    /// assert!(dict.upsert_param("a", "Key", BareItem::Integer(1)).is_err());
+    /// ```
+    fn upsert_param(&mut self, key: &str, param: &str, value: BareItem) -> SFVResult<()>;
+
+    /// Iterates over parameters of all `Dictionary` members in the order they appear in serialized form,
+    /// i.e. parameters of inner list items precede parameters of the `InnerList` itself.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser};
+    /// let dict = Parser::parse_dictionary("a=1;x, b=(2;y 3);z".as_bytes()).unwrap();
+    /// let keys: Vec<&str> = dict.params_iter().map(|(key, _)| key).collect();
+    /// assert_eq!(keys, vec!["x", "y", "z"]);
+    /// ```
+    fn params_iter(&self) -> impl Iterator<Item = (&str, &BareItem)>;
+
+    /// Removes all `Parameters` of `Dictionary` members, including the ones of inner list items.
+    /// ```
+    /// # use sfv::{DictionaryExt, Parser, SerializeValue};
+    /// let mut dict = Parser::parse_dictionary("a=1;x, b;y, c=(2;z)".as_bytes()).unwrap();
+    /// dict.strip_params();
+    /// assert_eq!(dict.serialize_value().unwrap(), "a=1, b, c=(2)");
+    /// ```
+    fn strip_params(&mut self);
+}
+
+impl DictionaryExt for Dictionary {
+    fn get_item(&self, key: &str) -> Option<&Item> {
+        self.get(key).and_then(ListEntry::as_item)
+    }
+
+    fn get_inner_list(&self, key: &str) -> Option<&InnerList> {
+        self.get(key).and_then(ListEntry::as_inner_list)
+    }
+
+    fn get_typed_item(&self, key: &str) -> DictMember<'_, Item> {
+        match self.get(key) {
+            None => DictMember::Absent,
+            Some(ListEntry::Item(item)) => DictMember::Present(item),
+            Some(ListEntry::InnerList(_)) => DictMember::WrongKind,
+        }
+    }
+
+    fn get_typed_inner_list(&self, key: &str) -> DictMember<'_, InnerList> {
+        match self.get(key) {
+            None => DictMember::Absent,
+            Some(ListEntry::InnerList(inner_list)) => DictMember::Present(inner_list),
+            Some(ListEntry::Item(_)) => DictMember::WrongKind,
+        }
+    }
+
+    fn iter_items(&self) -> impl Iterator<Item = (&String, &Item)> {
+        self.iter()
+            .filter_map(|(key, member)| member.as_item().map(|item| (key, item)))
+    }
+
+    fn iter_inner_lists(&self) -> impl Iterator<Item = (&String, &InnerList)> {
+        self.iter()
+            .filter_map(|(key, member)| member.as_inner_list().map(|inner_list| (key, inner_list)))
+    }
+
+    fn from_pairs<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> SFVResult<Dictionary>
+    where
+        K: Into<String>,
+        V: Into<ListEntry>,
+    {
+        let mut dict = Dictionary::default();
+        for (key, value) in pairs {
+            let key = key.into();
+            serializer::Serializer::validate_key(&key)?;
+            dict.insert(key, value.into());
+        }
+        Ok(dict)
+    }
+
+    fn compact(&mut self) {
+        self.values_mut().for_each(ListEntry::compact);
+        self.shrink_to_fit();
+    }
+
+    fn retain_entries(&mut self, mut keep: impl FnMut(&str, &ListEntry) -> bool) {
+        self.retain(|key, member| keep(key, member));
+    }
+
+    fn upsert_param(&mut self, key: &str, param: &str, value: BareItem) -> SFVResult<()> {
+        serializer::Serializer::validate_key(key)?;
+        serializer::Serializer::validate_key(param)?;
+        serializer::Serializer::validate_bare_item(&value)?;
+
+        let member = self
+            .entry(key.into())
+            .or_insert_with(|| Item::new(BareItem::Boolean(true)).into());
+        let params = match member {
+            ListEntry::Item(item) => &mut item.params,
+            ListEntry::InnerList(inner_list) => &mut inner_list.params,
+        };
+        params.insert(param.into(), value);
+        Ok(())
+    }
+
+    fn params_iter(&self) -> impl Iterator<Item = (&str, &BareItem)> {
+        self.values().flat_map(ListEntry::params_iter)
+    }
+
+    fn strip_params(&mut self) {
+        self.values_mut().for_each(ListEntry::strip_params);
+    }
+}
+
+/// Result of looking up a `Dictionary` member of expected kind,
+/// as returned by `DictionaryExt::get_typed_item` and `DictionaryExt::get_typed_inner_list`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DictMember<'a, T> {
+    /// `Dictionary` has no member with such key.
+    Absent,
+    /// Member is present, but it's an `Item` when `InnerList` was expected or vice versa.
+    WrongKind,
+    /// Member is present and is of expected kind.
+    Present(&'a T),
+}
+
+impl<'a, T> DictMember<'a, T> {
+    /// Returns the member if it's present and is of expected kind, otherwise returns `None`.
+    pub fn present(self) -> Option<&'a T> {
+        match self {
+            DictMember::Present(value) => Some(value),
+            DictMember::Absent | DictMember::WrongKind => None,
+        }
+    }
+}
 
 /// Represents `List` type structured field value.
 // sf-list       = list-member *( OWS "," OWS list-member )
 // list-member   = sf-item / inner-list
 pub type List = Vec<ListEntry>;
 
+/// Extends `List` with helpers for adding members without explicit `ListEntry` conversion.
+pub trait ListExt {
+    /// Appends `Item` to the end of the `List`.
+    /// ```
+    /// # use sfv::{BareItem, Item, List, ListExt, SerializeValue};
+    /// let mut list = List::new();
+    /// list.push_item(Item::new(BareItem::Integer(1)));
+    /// assert_eq!(list.serialize_value().unwrap(), "1");
+    /// ```
+    fn push_item(&mut self, item: Item);
+
+    /// Appends `InnerList` to the end of the `List`.
+    /// ```
+    /// # use sfv::{BareItem, InnerList, Item, List, ListExt, SerializeValue};
+    /// let mut list = List::new();
+    /// list.push_inner_list(InnerList::new(vec![Item::new(BareItem::Integer(1))]));
+    /// assert_eq!(list.serialize_value().unwrap(), "(1)");
+    /// ```
+    fn push_inner_list(&mut self, inner_list: InnerList);
+
+    /// Returns tokens of the `List` paired with their `Parameters`.
+    /// Fails if any member is not an `Item` with a `Token` bare item.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr, ListExt, Parser};
+    /// let list = Parser::parse_list("gzip;q=1.0, br;q=0.8".as_bytes()).unwrap();
+    /// let tokens = list.as_token_list().unwrap();
+    /// assert_eq!(tokens.len(), 2);
+    /// assert_eq!(tokens[0].0, "gzip");
+    /// assert_eq!(tokens[1].0, "br");
+    /// assert_eq!(tokens[1].1["q"], BareItem::Decimal(Decimal::from_str("0.8").unwrap()));
+    ///
+    /// let list = Parser::parse_list("gzip, \"br\"".as_bytes()).unwrap();
+    /// assert_eq!(list.as_token_list(), Err("as_token_list: list member is not a token"));
+    /// ```
+    fn as_token_list(&self) -> SFVResult<Vec<(&str, &Parameters)>>;
+
+    /// Shrinks capacity of the `List`, its inner lists and parameters as much as possible.
+    /// Useful for parsed values which are kept for a long time.
+    fn compact(&mut self);
+
+    /// Iterates over parameters of all `List` members in the order they appear in serialized form,
+    /// i.e. parameters of inner list items precede parameters of the `InnerList` itself.
+    /// ```
+    /// # use sfv::{ListExt, Parser};
+    /// let list = Parser::parse_list("1;a, (2;b 3;c);d".as_bytes()).unwrap();
+    /// let keys: Vec<&str> = list.params_iter().map(|(key, _)| key).collect();
+    /// assert_eq!(keys, vec!["a", "b", "c", "d"]);
+    /// ```
+    fn params_iter(&self) -> impl Iterator<Item = (&str, &BareItem)>;
+
+    /// Removes all `Parameters` of `List` members, including the ones of inner list items,
+    /// e.g. to compute a cache key from values only.
+    /// ```
+    /// # use sfv::{ListExt, Parser, SerializeValue};
+    /// let mut list = Parser::parse_list("1;a, (2;b 3);c".as_bytes()).unwrap();
+    /// list.strip_params();
+    /// assert_eq!(list.serialize_value().unwrap(), "1, (2 3)");
+    /// ```
+    fn strip_params(&mut self);
+}
+
+impl ListExt for List {
+    fn push_item(&mut self, item: Item) {
+        self.push(ListEntry::Item(item));
+    }
+
+    fn push_inner_list(&mut self, inner_list: InnerList) {
+        self.push(ListEntry::InnerList(inner_list));
+    }
+
+    fn as_token_list(&self) -> SFVResult<Vec<(&str, &Parameters)>> {
+        self.iter()
+            .map(|member| {
+                member
+                    .as_item()
+                    .and_then(|item| Some((item.bare_item.as_token()?, &item.params)))
+                    .ok_or("as_token_list: list member is not a token")
+            })
+            .collect()
+    }
+
+    fn compact(&mut self) {
+        self.iter_mut().for_each(ListEntry::compact);
+        self.shrink_to_fit();
+    }
+
+    fn params_iter(&self) -> impl Iterator<Item = (&str, &BareItem)> {
+        self.iter().flat_map(ListEntry::params_iter)
+    }
+
+    fn strip_params(&mut self) {
+        self.iter_mut().for_each(ListEntry::strip_params);
+    }
+}
+
 /// Parameters of `Item` or `InnerList`.
 // parameters    = *( ";" *SP parameter )
 // parameter     = param-name [ "=" param-value ]
@@ -233,10 +859,226 @@ pub type List = Vec<ListEntry>;
 //                 *( lcalpha / DIGIT / "_" / "-" / "." / "*" )
 // lcalpha       = %x61-7A ; a-z
 // param-value   = bare-item
-pub type Parameters = IndexMap<String, BareItem>;
+pub type Parameters = IndexMap<String, BareItem, HashBuilder>;
+
+/// Extends `Parameters` with helpers for working with them in place.
+pub trait ParametersExt {
+    /// Keeps only parameters for which `keep` returns `true`, preserving their order.
+    /// ```
+    /// # use sfv::{ParametersExt, Parser, SerializeValue};
+    /// let mut item = Parser::parse_item("tok;a=1;internal;b=2".as_bytes()).unwrap();
+    /// item.params.retain_entries(|key, _| key != "internal");
+    /// assert_eq!(item.serialize_value().unwrap(), "tok;a=1;b=2");
+    /// ```
+    fn retain_entries(&mut self, keep: impl FnMut(&str, &BareItem) -> bool);
+
+    /// Builds `Parameters` from key-value pairs, validating keys and values.
+    /// Unlike inserting into `Parameters`, fails if a key occurs more than once rather than overwriting its value,
+    /// e.g. to catch bugs in code which flattens some other structure into parameters.
+    /// Errors are returned along with the key of the offending pair.
+    /// ```
+    /// # use sfv::{BareItem, Parameters, ParametersExt};
+    /// let params = Parameters::try_from_pairs(vec![("a", BareItem::Integer(1)), ("b", true.into())]).unwrap();
+    /// assert_eq!(params.len(), 2);
+    ///
+    /// let params = Parameters::try_from_pairs(vec![("a", 1), ("b", 2), ("a", 3)]);
+    /// assert_eq!(params, Err(("a".to_owned(), "try_from_pairs: duplicate key")));
+    /// ```
+    fn try_from_pairs<K, V>(
+        pairs: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, (String, &'static str)>
+    where
+        Self: Sized,
+        K: Into<String>,
+        V: Into<BareItem>;
+
+    /// Returns value of parameter `key` converted into `T`,
+    /// or `None` if there's no such parameter or its value is of another type.
+    /// ```
+    /// # use sfv::{ParametersExt, Parser};
+    /// let item = Parser::parse_item("tok;count=3;debug".as_bytes()).unwrap();
+    /// assert_eq!(item.params.get_as::<i64>("count"), Some(3));
+    /// assert_eq!(item.params.get_as::<bool>("debug"), Some(true));
+    /// assert_eq!(item.params.get_as::<bool>("count"), None);
+    /// assert_eq!(item.params.get_as::<i64>("missing"), None);
+    /// ```
+    fn get_as<T: FromBareItem>(&self, key: &str) -> Option<T>;
+}
+
+impl ParametersExt for Parameters {
+    fn retain_entries(&mut self, mut keep: impl FnMut(&str, &BareItem) -> bool) {
+        self.retain(|key, value| keep(key, value));
+    }
+
+    fn try_from_pairs<K, V>(
+        pairs: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Parameters, (String, &'static str)>
+    where
+        K: Into<String>,
+        V: Into<BareItem>,
+    {
+        let mut params = Parameters::default();
+        for (key, value) in pairs {
+            let key = key.into();
+            let value = value.into();
+            let validated = serializer::Serializer::validate_key(&key)
+                .and_then(|_| serializer::Serializer::validate_bare_item(&value));
+            if let Err(err) = validated {
+                return Err((key, err));
+            }
+            if params.contains_key(&key) {
+                return Err((key, "try_from_pairs: duplicate key"));
+            }
+            params.insert(key, value);
+        }
+        Ok(params)
+    }
+
+    fn get_as<T: FromBareItem>(&self, key: &str) -> Option<T> {
+        self.get(key).and_then(T::from_bare_item)
+    }
+}
+
+/// Conversion from `BareItem` of the matching type, used by `ParametersExt::get_as`.
+/// Implemented for:
+/// - `i64`, from `Integer`,
+/// - `Decimal`, from `Decimal`,
+/// - `bool`, from `Boolean`,
+/// - `String`, from `String` only, use `BareItem::as_token` or `BareItem::as_text` for tokens,
+/// - `Vec<u8>`, from `ByteSeq`.
+/// ```
+/// # use sfv::{Decimal, FromStr, ParametersExt, Parser};
+/// let item = Parser::parse_item(r#"1;i=5;d=0.5;b=?0;s="str";t=tok;bs=:aGk=:"#.as_bytes()).unwrap();
+/// let params = &item.params;
+/// assert_eq!(params.get_as::<i64>("i"), Some(5));
+/// assert_eq!(params.get_as::<Decimal>("d"), Some(Decimal::from_str("0.5").unwrap()));
+/// assert_eq!(params.get_as::<bool>("b"), Some(false));
+/// assert_eq!(params.get_as::<String>("s"), Some("str".to_owned()));
+/// assert_eq!(params.get_as::<String>("t"), None);
+/// assert_eq!(params.get_as::<Vec<u8>>("bs"), Some(b"hi".to_vec()));
+/// ```
+pub trait FromBareItem: Sized {
+    /// Returns value of `bare_item` if it's of the matching type, otherwise returns `None`.
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self>;
+}
+
+impl FromBareItem for i64 {
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self> {
+        bare_item.as_int()
+    }
+}
+
+impl FromBareItem for Decimal {
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self> {
+        bare_item.as_decimal()
+    }
+}
+
+impl FromBareItem for bool {
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self> {
+        bare_item.as_bool()
+    }
+}
+
+impl FromBareItem for String {
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self> {
+        bare_item.as_str().map(String::from)
+    }
+}
+
+impl FromBareItem for Vec<u8> {
+    fn from_bare_item(bare_item: &BareItem) -> Option<Self> {
+        bare_item.as_bytes().map(<[u8]>::to_vec)
+    }
+}
+
+/// Builds `Parameters`, validating keys and values as they are inserted
+/// rather than when they are serialized.
+/// ```
+/// # use sfv::{BareItem, Item, ParametersBuilder, SerializeValue};
+/// let mut builder = ParametersBuilder::new();
+/// builder.insert("a", 1)?.insert("b", BareItem::Token("tok".into()))?;
+///
+/// assert!(builder.insert("Key", 1).is_err());
+/// assert!(builder.insert("c", 1_000_000_000_000_000).is_err());
+/// assert!(builder.insert("c", BareItem::String("non-ascii \u{1f439}".into())).is_err());
+///
+/// let item = Item::with_params(BareItem::Boolean(true), builder.build());
+/// assert_eq!(item.serialize_value()?, "?1;a=1;b=tok");
+/// # Ok::<(), &'static str>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ParametersBuilder {
+    params: Parameters,
+}
+
+impl ParametersBuilder {
+    /// Returns new `ParametersBuilder` without parameters.
+    pub fn new() -> ParametersBuilder {
+        ParametersBuilder::default()
+    }
+
+    /// Validates and inserts parameter. If parameter with the same key already exists, its value is replaced.
+    /// Nothing is inserted if either key or value is not valid, or value can't be converted into `BareItem`.
+    /// As conversion errors vary by type, they are all reported as the same error.
+    pub fn insert<T: TryInto<BareItem>>(&mut self, key: &str, value: T) -> SFVResult<&mut Self> {
+        let value = value
+            .try_into()
+            .map_err(|_| "parameters_builder: value can't be converted into bare item")?;
+        serializer::Serializer::validate_key(key)?;
+        serializer::Serializer::validate_bare_item(&value)?;
+        self.params.insert(key.into(), value);
+        Ok(self)
+    }
+
+    /// Returns built `Parameters`.
+    pub fn build(self) -> Parameters {
+        self.params
+    }
+}
+
+/// Builds `InnerList` adding `Items` and parameters one by one.
+/// Parameters are validated the same way as by `ParametersBuilder`.
+/// ```
+/// # use sfv::{BareItem, InnerList, Item, List, SerializeValue};
+/// let inner_list = InnerList::builder()
+///     .item(Item::new(BareItem::Token("a".into())))
+///     .item(Item::new(BareItem::Token("b".into())))
+///     .param("key", BareItem::Token("val".into()))?
+///     .build();
+/// let list: List = vec![inner_list.into()];
+/// assert_eq!(list.serialize_value()?, "(a b);key=val");
+///
+/// assert!(InnerList::builder().param("Key", 1).is_err());
+/// # Ok::<(), &'static str>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InnerListBuilder {
+    items: Vec<Item>,
+    params: ParametersBuilder,
+}
+
+impl InnerListBuilder {
+    /// Appends `Item` to the end of the `InnerList`.
+    pub fn item(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Validates and inserts `InnerList` parameter. If parameter with the same key already exists, its value is replaced.
+    pub fn param<T: TryInto<BareItem>>(mut self, key: &str, value: T) -> SFVResult<Self> {
+        self.params.insert(key, value)?;
+        Ok(self)
+    }
+
+    /// Returns built `InnerList`.
+    pub fn build(self) -> InnerList {
+        InnerList::with_params(self.items, self.params.build())
+    }
+}
 
 /// Represents a member of `List` or `Dictionary` structured field value.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum ListEntry {
     /// Member of `Item` type.
     Item(Item),
@@ -244,6 +1086,128 @@ pub enum ListEntry {
     InnerList(InnerList),
 }
 
+impl ListEntry {
+    // Parameters of the member and of its inner list items, in the order of serialized form
+    fn params_iter(&self) -> impl Iterator<Item = (&str, &BareItem)> {
+        let (items, params): (&[Item], &Parameters) = match self {
+            ListEntry::Item(item) => (&[], &item.params),
+            ListEntry::InnerList(inner_list) => (&inner_list.items, &inner_list.params),
+        };
+        items
+            .iter()
+            .flat_map(|item| item.params.iter())
+            .chain(params.iter())
+            .map(|(key, value)| (key.as_str(), value))
+    }
+
+    pub(crate) fn compact(&mut self) {
+        match self {
+            ListEntry::Item(item) => item.compact(),
+            ListEntry::InnerList(inner_list) => inner_list.compact(),
+        }
+    }
+
+    /// Removes all `Parameters` of the member, including the ones of inner list items.
+    pub fn strip_params(&mut self) {
+        match self {
+            ListEntry::Item(item) => item.strip_params(),
+            ListEntry::InnerList(inner_list) => inner_list.strip_params(),
+        }
+    }
+
+    /// Returns `true` if `ListEntry` is an `Item`.
+    /// ```
+    /// # use sfv::{BareItem, Item, ListEntry};
+    /// let entry: ListEntry = Item::new(BareItem::Integer(1)).into();
+    /// assert!(entry.is_item());
+    /// ```
+    pub fn is_item(&self) -> bool {
+        matches!(self, ListEntry::Item(_))
+    }
+    /// Returns `true` if `ListEntry` is an `InnerList`.
+    /// ```
+    /// # use sfv::{InnerList, ListEntry};
+    /// let entry: ListEntry = InnerList::new(vec![]).into();
+    /// assert!(entry.is_inner_list());
+    /// ```
+    pub fn is_inner_list(&self) -> bool {
+        matches!(self, ListEntry::InnerList(_))
+    }
+    /// If `ListEntry` is an `Item`, returns `&Item`, otherwise returns `None`.
+    /// ```
+    /// # use sfv::{BareItem, Item, ListEntry};
+    /// let entry: ListEntry = Item::new(BareItem::Integer(1)).into();
+    /// assert_eq!(entry.as_item().unwrap().bare_item, BareItem::Integer(1));
+    /// assert!(entry.as_inner_list().is_none());
+    /// ```
+    pub fn as_item(&self) -> Option<&Item> {
+        match *self {
+            ListEntry::Item(ref item) => Some(item),
+            _ => None,
+        }
+    }
+    /// If `ListEntry` is an `InnerList`, returns `&InnerList`, otherwise returns `None`.
+    /// ```
+    /// # use sfv::{InnerList, ListEntry};
+    /// let entry: ListEntry = InnerList::new(vec![]).into();
+    /// assert!(entry.as_inner_list().unwrap().items.is_empty());
+    /// assert!(entry.as_item().is_none());
+    /// ```
+    pub fn as_inner_list(&self) -> Option<&InnerList> {
+        match *self {
+            ListEntry::InnerList(ref inner_list) => Some(inner_list),
+            _ => None,
+        }
+    }
+    /// If `ListEntry` is an `Item`, returns it, otherwise returns `None`.
+    /// ```
+    /// # use sfv::{BareItem, Item, ListEntry};
+    /// let entry: ListEntry = Item::new(BareItem::Integer(1)).into();
+    /// assert_eq!(entry.into_item(), Some(Item::new(BareItem::Integer(1))));
+    /// ```
+    pub fn into_item(self) -> Option<Item> {
+        match self {
+            ListEntry::Item(item) => Some(item),
+            _ => None,
+        }
+    }
+    /// If `ListEntry` is an `InnerList`, returns it, otherwise returns `None`.
+    /// ```
+    /// # use sfv::{InnerList, ListEntry};
+    /// let entry: ListEntry = InnerList::new(vec![]).into();
+    /// assert_eq!(entry.into_inner_list(), Some(InnerList::new(vec![])));
+    /// ```
+    pub fn into_inner_list(self) -> Option<InnerList> {
+        match self {
+            ListEntry::InnerList(inner_list) => Some(inner_list),
+            _ => None,
+        }
+    }
+    /// Applies `f` if `ListEntry` is an `Item`, otherwise returns `InnerList` unchanged.
+    /// ```
+    /// # use sfv::{BareItem, List, Parser, SerializeValue};
+    /// let list = Parser::parse_list("1, (2 3), 4;a, foo".as_bytes()).unwrap();
+    /// let list: List = list
+    ///     .into_iter()
+    ///     .map(|entry| {
+    ///         entry.map_item(|item| {
+    ///             item.map_bare_item(|bare_item| match bare_item {
+    ///                 BareItem::Integer(val) => BareItem::Integer(val * 2),
+    ///                 other => other,
+    ///             })
+    ///         })
+    ///     })
+    ///     .collect();
+    /// assert_eq!(list.serialize_value().unwrap(), "2, (2 3), 8;a, foo");
+    /// ```
+    pub fn map_item(self, f: impl FnOnce(Item) -> Item) -> ListEntry {
+        match self {
+            ListEntry::Item(item) => ListEntry::Item(f(item)),
+            inner_list => inner_list,
+        }
+    }
+}
+
 impl From<Item> for ListEntry {
     fn from(item: Item) -> Self {
         ListEntry::Item(item)
@@ -259,7 +1223,7 @@ impl From<InnerList> for ListEntry {
 /// Array of `Items` with associated `Parameters`.
 // inner-list    = "(" *SP [ sf-item *( 1*SP sf-item ) *SP ] ")"
 //                 parameters
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct InnerList {
     /// `Items` that `InnerList` contains. Can be empty.
     pub items: Vec<Item>,
@@ -267,12 +1231,20 @@ pub struct InnerList {
     pub params: Parameters,
 }
 
+impl Hash for InnerList {
+    /// Hashes `Items` in order and `Parameters` in order of their keys, same as `Item` does.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+        hash_params(&self.params, state);
+    }
+}
+
 impl InnerList {
     /// Returns new `InnerList` with empty `Parameters`.
     pub fn new(items: Vec<Item>) -> InnerList {
         InnerList {
             items,
-            params: Parameters::new(),
+            params: Parameters::default(),
         }
     }
 
@@ -280,10 +1252,130 @@ impl InnerList {
     pub fn with_params(items: Vec<Item>, params: Parameters) -> InnerList {
         InnerList { items, params }
     }
+
+    /// Returns new `InnerListBuilder` for building `InnerList` from `Items` and validated parameters.
+    pub fn builder() -> InnerListBuilder {
+        InnerListBuilder::default()
+    }
+
+    /// Inserts parameters from `params` into `InnerList`'s `Parameters`, validating all keys and values first.
+    /// If any parameter is invalid, returns its key along with the error and leaves `Parameters` unchanged.
+    pub fn extend_params<I: IntoIterator<Item = (String, BareItem)>>(
+        &mut self,
+        params: I,
+    ) -> Result<(), (String, &'static str)> {
+        extend_params(&mut self.params, params)
+    }
+
+    /// Returns the number of `Items` in the `InnerList`.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the `InnerList` contains no `Items`.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the first `Item` of the `InnerList`, or `None` if it's empty.
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let list = Parser::parse_list("(1;a 2 3)".as_bytes()).unwrap();
+    /// let inner_list = list[0].as_inner_list().unwrap();
+    /// assert_eq!(inner_list.first().unwrap().bare_item, BareItem::Integer(1));
+    /// ```
+    pub fn first(&self) -> Option<&Item> {
+        self.items.first()
+    }
+
+    /// Returns `Items` of the `InnerList` as a slice, without `InnerList` parameters.
+    /// As inner lists can't be nested, these are all the bare items and their parameters it contains.
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let list = Parser::parse_list("(1;a 2);b".as_bytes()).unwrap();
+    /// let items = list[0].as_inner_list().unwrap().flatten_items();
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(items[1].bare_item, BareItem::Integer(2));
+    /// ```
+    pub fn flatten_items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// If the first `Item` of the `InnerList` is an `Integer`, returns its value, otherwise returns `None`.
+    /// ```
+    /// # use sfv::Parser;
+    /// let list = Parser::parse_list("(1 2 3), (\"a\" 2), ()".as_bytes()).unwrap();
+    /// assert_eq!(list[0].as_inner_list().unwrap().first_int(), Some(1));
+    /// assert_eq!(list[1].as_inner_list().unwrap().first_int(), None);
+    /// assert_eq!(list[2].as_inner_list().unwrap().first_int(), None);
+    /// ```
+    pub fn first_int(&self) -> Option<i64> {
+        self.first()?.bare_item.as_int()
+    }
+
+    /// If the first `Item` of the `InnerList` is a `String`, returns its value, otherwise returns `None`.
+    /// ```
+    /// # use sfv::Parser;
+    /// let list = Parser::parse_list("(\"a\" 2), (1 \"b\")".as_bytes()).unwrap();
+    /// assert_eq!(list[0].as_inner_list().unwrap().first_str(), Some("a"));
+    /// assert_eq!(list[1].as_inner_list().unwrap().first_str(), None);
+    /// ```
+    pub fn first_str(&self) -> Option<&str> {
+        self.first()?.bare_item.as_str()
+    }
+
+    /// Sorts `Parameters` of the `InnerList` and of each of its `Items` by key.
+    /// Order of `Items` is preserved.
+    ///
+    /// Note that parameters order is significant in RFC 8941 and this changes serialized output.
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let list = Parser::parse_list("(1;z;y 2);b;a".as_bytes()).unwrap();
+    /// let mut inner_list = list[0].as_inner_list().unwrap().clone();
+    /// inner_list.sort_params();
+    /// assert_eq!(vec![inner_list.into()].serialize_value().unwrap(), "(1;y;z 2);a;b");
+    /// ```
+    pub fn sort_params(&mut self) {
+        for item in self.items.iter_mut() {
+            item.sort_params();
+        }
+        self.params.sort_keys();
+    }
+
+    /// Removes all `Parameters` of `InnerList` and of its `Items`.
+    /// ```
+    /// # use sfv::{InnerList, Parser};
+    /// let list = Parser::parse_list("(1;a 2;b);c".as_bytes()).unwrap();
+    /// let mut inner_list = list[0].as_inner_list().unwrap().clone();
+    /// inner_list.strip_params();
+    /// assert_eq!(inner_list.to_string(), "(1 2)");
+    /// ```
+    pub fn strip_params(&mut self) {
+        self.items.iter_mut().for_each(Item::strip_params);
+        self.params.clear();
+    }
+
+    pub(crate) fn compact(&mut self) {
+        self.items.iter_mut().for_each(Item::compact);
+        self.items.shrink_to_fit();
+        self.params.shrink_to_fit();
+    }
 }
 
 /// `BareItem` type is used to construct `Items` or `Parameters` values.
-#[derive(Debug, PartialEq, Clone)]
+///
+/// Variants are constructed directly from their values without any validation,
+/// so constructing a `Token` or `String` from already validated input has no extra cost.
+/// RFC 8941 grammar and range constraints are checked only once, during serialization.
+///
+/// None of the variants hold floating point numbers, so `BareItem` implements `Eq` and `Hash`.
+/// Decimals are compared by value, e.g. `2.5` is equal to `2.50`.
+/// ```
+/// # use sfv::{BareItem, Item, SerializeValue};
+/// let token = BareItem::Token("7invalid".into());
+/// assert!(Item::new(token).serialize_value().is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum BareItem {
     /// Decimal number
     // sf-decimal  = ["-"] 1*12DIGIT "." 1*3DIGIT
@@ -307,6 +1399,59 @@ pub enum BareItem {
 }
 
 impl BareItem {
+    /// Returns `BareItem::Integer` if `value` fits into `[MIN_INTEGER, MAX_INTEGER]` range, otherwise fails.
+    /// Accepts any integer type, e.g. `u64` timestamps, so there's no need to convert it to `i64` first.
+    /// ```
+    /// # use sfv::{BareItem, MAX_INTEGER};
+    /// assert_eq!(BareItem::new_integer_checked(42_u64), Ok(BareItem::Integer(42)));
+    /// assert_eq!(BareItem::new_integer_checked(MAX_INTEGER), Ok(BareItem::Integer(MAX_INTEGER)));
+    /// assert!(BareItem::new_integer_checked(u64::MAX).is_err());
+    /// ```
+    pub fn new_integer_checked<T: TryInto<i64>>(value: T) -> SFVResult<BareItem> {
+        let value = value
+            .try_into()
+            .map_err(|_| "new_integer_checked: integer is out of range")?;
+        if !(MIN_INTEGER..=MAX_INTEGER).contains(&value) {
+            return Err("new_integer_checked: integer is out of range");
+        }
+        Ok(BareItem::Integer(value))
+    }
+    /// Returns `BareItem::Decimal` equal to `mantissa * 10^-scale`, built exactly, without going through `f64`.
+    /// Fractional part is rounded half to even to three digits, the same way as on serialization,
+    /// and fails if integer part has more than 12 digits or `scale` exceeds 28.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// let bare_item = BareItem::new_decimal_from_parts(1337, 2).unwrap();
+    /// assert_eq!(bare_item, BareItem::Decimal(Decimal::from_str("13.37").unwrap()));
+    /// assert_eq!(BareItem::new_decimal_from_parts(12345, 4).unwrap().as_decimal().unwrap().to_string(), "1.234");
+    /// assert!(BareItem::new_decimal_from_parts(1_000_000_000_000_000, 3).is_err());
+    /// ```
+    pub fn new_decimal_from_parts(mantissa: i64, scale: u8) -> SFVResult<BareItem> {
+        let value = Decimal::try_new(mantissa, u32::from(scale))
+            .map_err(|_| "new_decimal_from_parts: scale is out of range")?
+            .round_dp(3);
+        serializer::Serializer::validate_decimal(value)
+            .map_err(|_| "new_decimal_from_parts: decimal is out of range")?;
+        Ok(BareItem::Decimal(value))
+    }
+    /// Returns `BareItem::Decimal` if `value` can be serialized without losing precision, otherwise fails.
+    /// Unlike serialization and `new_decimal_from_parts`, which round fractional part to three digits,
+    /// fails if `value` has more than three significant fractional digits, e.g. to detect truncation of monetary values.
+    /// Trailing zeros don't count, so `1.5000` is accepted.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// let value = Decimal::from_str("13.370").unwrap();
+    /// assert_eq!(BareItem::new_decimal_exact(value), Ok(BareItem::Decimal(value)));
+    /// assert!(BareItem::new_decimal_exact(Decimal::from_str("13.3705").unwrap()).is_err());
+    /// ```
+    pub fn new_decimal_exact(value: Decimal) -> SFVResult<BareItem> {
+        if value.normalize().scale() > 3 {
+            return Err("new_decimal_exact: more than three fractional digits");
+        }
+        serializer::Serializer::validate_decimal(value)
+            .map_err(|_| "new_decimal_exact: decimal is out of range")?;
+        Ok(BareItem::Decimal(value))
+    }
     /// If `BareItem` is a decimal, returns `Decimal`, otherwise returns `None`.
     /// ```
     /// # use sfv::{BareItem, Decimal, FromPrimitive};
@@ -320,6 +1465,24 @@ impl BareItem {
             _ => None,
         }
     }
+    /// If `BareItem` is a decimal or an integer, returns its value as `Decimal`, otherwise returns `None`.
+    /// Integers are converted exactly, as their range fits into `Decimal`.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// assert_eq!(BareItem::Integer(42).to_decimal(), Some(Decimal::from(42)));
+    /// assert_eq!(
+    ///     BareItem::Decimal(Decimal::from_str("3.14").unwrap()).to_decimal(),
+    ///     Some(Decimal::from_str("3.14").unwrap())
+    /// );
+    /// assert_eq!(BareItem::Token("a".into()).to_decimal(), None);
+    /// ```
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        match *self {
+            BareItem::Decimal(val) => Some(val),
+            BareItem::Integer(val) => Some(Decimal::from(val)),
+            _ => None,
+        }
+    }
     /// If `BareItem` is an integer, returns `i64`, otherwise returns `None`.
     /// ```
     /// # use sfv::BareItem;
@@ -345,17 +1508,45 @@ impl BareItem {
         }
     }
     /// If `BareItem` is a `ByteSeq`, returns `&Vec<u8>`, otherwise returns `None`.
+    /// Prefer `as_bytes`, which returns a slice.
     /// ```
+    /// # #![allow(deprecated)]
     /// # use sfv::BareItem;
     /// let bare_item = BareItem::ByteSeq("foo".to_owned().into_bytes());
     /// assert_eq!(bare_item.as_byte_seq().unwrap().as_slice(), "foo".as_bytes());
     /// ```
+    #[deprecated(since = "0.9.4", note = "use `as_bytes` instead")]
     pub fn as_byte_seq(&self) -> Option<&Vec<u8>> {
         match *self {
             BareItem::ByteSeq(ref val) => Some(val),
             _ => None,
         }
     }
+    /// If `BareItem` is a `ByteSeq`, returns `&[u8]`, otherwise returns `None`.
+    /// ```
+    /// # use sfv::BareItem;
+    /// let bare_item = BareItem::ByteSeq("foo".to_owned().into_bytes());
+    /// assert_eq!(bare_item.as_bytes().unwrap(), "foo".as_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match *self {
+            BareItem::ByteSeq(ref val) => Some(val),
+            _ => None,
+        }
+    }
+    /// If `BareItem` is a `ByteSeq`, returns its bytes without copying them, otherwise returns `None`.
+    /// ```
+    /// # use sfv::BareItem;
+    /// let bare_item = BareItem::ByteSeq("foo".to_owned().into_bytes());
+    /// assert_eq!(bare_item.into_bytes().unwrap(), b"foo".to_vec());
+    /// assert!(BareItem::Integer(1).into_bytes().is_none());
+    /// ```
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            BareItem::ByteSeq(val) => Some(val),
+            _ => None,
+        }
+    }
     /// If `BareItem` is a `Boolean`, returns `bool`, otherwise returns `None`.
     /// ```
     /// # use sfv::{BareItem, Decimal, FromPrimitive};
@@ -381,6 +1572,107 @@ impl BareItem {
             _ => None,
         }
     }
+    /// Returns `true` if `BareItem` is a `Token` equal to `other` ignoring ASCII case, e.g. when comparing to `gzip`.
+    /// Tokens are case-sensitive per RFC 8941, so use it only where the field's own specification
+    /// defines case-insensitive comparison, e.g. for media types.
+    /// ```
+    /// # use sfv::BareItem;
+    /// assert!(BareItem::Token("GZip".into()).token_eq_ignore_ascii_case("gzip"));
+    /// assert!(!BareItem::Token("gzip".into()).token_eq_ignore_ascii_case("br"));
+    /// assert!(!BareItem::String("gzip".into()).token_eq_ignore_ascii_case("gzip"));
+    /// ```
+    pub fn token_eq_ignore_ascii_case(&self, other: &str) -> bool {
+        match *self {
+            BareItem::Token(ref val) => val.eq_ignore_ascii_case(other),
+            _ => false,
+        }
+    }
+    /// If `BareItem` is a `Token` or a `String`, returns `&str`, otherwise returns `None`.
+    /// Intentionally doesn't tell tokens and strings apart,
+    /// for fields which accept either of them for the same value.
+    /// ```
+    /// # use sfv::BareItem;
+    /// assert_eq!(BareItem::Token("foo".into()).as_text(), Some("foo"));
+    /// assert_eq!(BareItem::String("foo".into()).as_text(), Some("foo"));
+    /// assert_eq!(BareItem::Integer(1).as_text(), None);
+    /// ```
+    pub fn as_text(&self) -> Option<&str> {
+        match *self {
+            BareItem::Token(ref val) | BareItem::String(ref val) => Some(val),
+            _ => None,
+        }
+    }
+    /// Compares numeric values of `Integer` and `Decimal` bare items,
+    /// promoting `Integer` to `Decimal` when comparing the two.
+    /// Returns `None` if either of bare items is not a number.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// # use std::cmp::Ordering;
+    /// let mut items = vec![
+    ///     BareItem::Integer(10),
+    ///     BareItem::Decimal(Decimal::from_str("9.5").unwrap()),
+    ///     BareItem::Decimal(Decimal::from_str("10.0").unwrap()),
+    /// ];
+    /// items.sort_by(|a, b| a.partial_cmp_numeric(b).unwrap());
+    /// assert_eq!(items[0], BareItem::Decimal(Decimal::from_str("9.5").unwrap()));
+    /// assert_eq!(
+    ///     items[1].partial_cmp_numeric(&items[2]),
+    ///     Some(Ordering::Equal)
+    /// );
+    /// assert_eq!(
+    ///     BareItem::Integer(1).partial_cmp_numeric(&BareItem::Token("a".into())),
+    ///     None
+    /// );
+    /// ```
+    pub fn partial_cmp_numeric(&self, other: &BareItem) -> Option<Ordering> {
+        Some(self.to_decimal()?.cmp(&other.to_decimal()?))
+    }
+    /// Returns the name of `BareItem` type as used in RFC 8941. Useful for diagnostics and logging.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// assert_eq!(BareItem::Integer(1).type_name(), "integer");
+    /// assert_eq!(BareItem::Decimal(Decimal::from_str("1.5").unwrap()).type_name(), "decimal");
+    /// assert_eq!(BareItem::String("foo".into()).type_name(), "string");
+    /// assert_eq!(BareItem::Token("foo".into()).type_name(), "token");
+    /// assert_eq!(BareItem::ByteSeq(vec![]).type_name(), "byte-sequence");
+    /// assert_eq!(BareItem::Boolean(true).type_name(), "boolean");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            BareItem::Decimal(_) => "decimal",
+            BareItem::Integer(_) => "integer",
+            BareItem::String(_) => "string",
+            BareItem::ByteSeq(_) => "byte-sequence",
+            BareItem::Boolean(_) => "boolean",
+            BareItem::Token(_) => "token",
+        }
+    }
+}
+
+impl Hash for BareItem {
+    /// Hashes `BareItem` variant along with its value.
+    /// Equal decimals may differ in scale, e.g. `2.5` and `2.50`, so their normalized value is hashed.
+    /// ```
+    /// # use sfv::{BareItem, Decimal, FromStr};
+    /// # use std::collections::HashSet;
+    /// let mut bare_items = HashSet::new();
+    /// bare_items.insert(BareItem::Decimal(Decimal::from_str("2.5").unwrap()));
+    /// bare_items.insert(BareItem::Decimal(Decimal::from_str("2.50").unwrap()));
+    /// bare_items.insert(BareItem::String("tok".into()));
+    /// bare_items.insert(BareItem::Token("tok".into()));
+    /// assert_eq!(bare_items.len(), 3);
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            BareItem::Decimal(val) => val.normalize().hash(state),
+            BareItem::Integer(val) => val.hash(state),
+            BareItem::String(val) => val.hash(state),
+            BareItem::ByteSeq(val) => val.hash(state),
+            BareItem::Boolean(val) => val.hash(state),
+            BareItem::Token(val) => val.hash(state),
+        }
+    }
 }
 
 impl From<i64> for BareItem {
@@ -395,6 +1687,18 @@ impl From<i64> for BareItem {
     }
 }
 
+impl From<bool> for BareItem {
+    /// Converts `bool` into `BareItem::Boolean`.
+    /// ```
+    /// # use sfv::BareItem;
+    /// let bare_item: BareItem = true.into();
+    /// assert_eq!(bare_item.as_bool().unwrap(), true);
+    /// ```
+    fn from(item: bool) -> Self {
+        BareItem::Boolean(item)
+    }
+}
+
 impl From<Decimal> for BareItem {
     /// Converts `Decimal` into `BareItem::Decimal`.
     /// ```
@@ -408,6 +1712,30 @@ impl From<Decimal> for BareItem {
     }
 }
 
+impl From<Vec<u8>> for BareItem {
+    /// Converts `Vec<u8>` into `BareItem::ByteSeq`.
+    /// ```
+    /// # use sfv::BareItem;
+    /// let bare_item: BareItem = b"bytes".to_vec().into();
+    /// assert_eq!(bare_item.as_bytes().unwrap(), b"bytes");
+    /// ```
+    fn from(item: Vec<u8>) -> Self {
+        BareItem::ByteSeq(item)
+    }
+}
+
+impl From<&[u8]> for BareItem {
+    /// Converts `&[u8]` into `BareItem::ByteSeq` by copying the bytes.
+    /// ```
+    /// # use sfv::BareItem;
+    /// let bare_item: BareItem = "bytes".as_bytes().into();
+    /// assert_eq!(bare_item, BareItem::ByteSeq(b"bytes".to_vec()));
+    /// ```
+    fn from(item: &[u8]) -> Self {
+        BareItem::ByteSeq(item.to_vec())
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub(crate) enum Num {
     Decimal(Decimal),
@@ -415,6 +1743,25 @@ pub(crate) enum Num {
 }
 
 /// Similar to `BareItem`, but used to serialize values via `RefItemSerializer`, `RefListSerializer`, `RefDictSerializer`.
+///
+/// Values are borrowed rather than owned, so serializing e.g. string literals doesn't copy them into a `String`.
+/// They're validated on serialization the same way `BareItem` values are.
+/// ```
+/// # use sfv::{RefBareItem, RefItemSerializer};
+/// const REPORT_TO: &str = "default-endpoint";
+///
+/// let mut output = String::new();
+/// RefItemSerializer::new(&mut output)
+///     .bare_item(&RefBareItem::Token("same-origin"))
+///     .unwrap()
+///     .parameter("report-to", &RefBareItem::String(REPORT_TO))
+///     .unwrap();
+/// assert_eq!(output, r#"same-origin;report-to="default-endpoint""#);
+///
+/// assert!(RefItemSerializer::new(&mut String::new())
+///     .bare_item(&RefBareItem::String("new\nline"))
+///     .is_err());
+/// ```
 #[derive(Debug, PartialEq, Clone)]
 pub enum RefBareItem<'a> {
     Integer(i64),
@@ -427,7 +1774,7 @@ pub enum RefBareItem<'a> {
 
 impl BareItem {
     /// Converts `BareItem` into `RefBareItem`.
-    fn to_ref_bare_item(&self) -> RefBareItem {
+    fn to_ref_bare_item(&self) -> RefBareItem<'_> {
         match self {
             BareItem::Integer(val) => RefBareItem::Integer(*val),
             BareItem::Decimal(val) => RefBareItem::Decimal(*val),
@@ -438,3 +1785,31 @@ impl BareItem {
         }
     }
 }
+
+/// Borrows `BareItem` as `RefBareItem`, e.g. to serialize parsed values via `RefListSerializer`.
+/// ```
+/// # use sfv::{BareItem, RefBareItem, RefListSerializer};
+/// let bare_item = BareItem::String("hello".into());
+/// let mut output = String::new();
+/// RefListSerializer::new(&mut output).bare_item(&RefBareItem::from(&bare_item)).unwrap();
+/// assert_eq!(output, "\"hello\"");
+/// ```
+impl<'a> From<&'a BareItem> for RefBareItem<'a> {
+    fn from(bare_item: &'a BareItem) -> RefBareItem<'a> {
+        bare_item.to_ref_bare_item()
+    }
+}
+
+/// Copies `RefBareItem` into an owned `BareItem`.
+impl From<RefBareItem<'_>> for BareItem {
+    fn from(ref_bare_item: RefBareItem<'_>) -> BareItem {
+        match ref_bare_item {
+            RefBareItem::Integer(val) => BareItem::Integer(val),
+            RefBareItem::Decimal(val) => BareItem::Decimal(val),
+            RefBareItem::String(val) => BareItem::String(val.into()),
+            RefBareItem::ByteSeq(val) => BareItem::ByteSeq(val.into()),
+            RefBareItem::Boolean(val) => BareItem::Boolean(val),
+            RefBareItem::Token(val) => BareItem::Token(val.into()),
+        }
+    }
+}
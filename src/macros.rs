@@ -0,0 +1,240 @@
+use crate::serializer::Serializer;
+use crate::{BareItem, Dictionary, Item, List, ListEntry, Parameters, SFVResult};
+use alloc::{string::String, vec::Vec};
+
+/// Defines an enum for a fixed vocabulary of tokens, e.g. values of `Content-Encoding`.
+///
+/// Each listed variant is mapped to its token, and an additional `Unknown(String)` variant
+/// keeps any other token, so that unrecognized values can be passed through.
+/// Generated enum implements:
+/// - `FromStr`, which fails only if input is not a valid token,
+/// - `TryFrom<&BareItem>`, which fails if bare item is not a valid `Token`,
+/// - `From<Enum> for BareItem`, which converts variant back into a `Token`,
+/// - `as_str`, which returns the token of variant.
+///
+/// Tokens are matched case-sensitively, as RFC 8941 tokens are case-sensitive.
+/// `Unknown` variant constructed directly rather than parsed is not validated,
+/// so it may hold an invalid token, which fails to serialize.
+/// # Examples
+/// ```
+/// use sfv::{token_enum, BareItem, Parser};
+/// use std::convert::TryFrom;
+///
+/// token_enum! {
+///     /// Content codings.
+///     pub enum ContentEncoding {
+///         Gzip => "gzip",
+///         Brotli => "br",
+///         Identity => "identity",
+///     }
+/// }
+///
+/// let item = Parser::parse_item("gzip".as_bytes()).unwrap();
+/// let encoding = ContentEncoding::try_from(&item.bare_item).unwrap();
+/// assert_eq!(encoding, ContentEncoding::Gzip);
+///
+/// let encoding: ContentEncoding = "zstd".parse().unwrap();
+/// assert_eq!(encoding, ContentEncoding::Unknown("zstd".into()));
+/// assert_eq!(BareItem::from(encoding), BareItem::Token("zstd".into()));
+///
+/// assert!(ContentEncoding::try_from(&BareItem::String("gzip".into())).is_err());
+/// assert!("not a token".parse::<ContentEncoding>().is_err());
+/// ```
+#[macro_export]
+macro_rules! token_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident => $token:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant,)+
+            /// Token which is not a part of the vocabulary.
+            Unknown($crate::__private::String),
+        }
+
+        impl $name {
+            /// Returns the token of variant.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $token,)+
+                    $name::Unknown(token) => token,
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = &'static str;
+
+            fn from_str(token: &str) -> ::core::result::Result<Self, Self::Err> {
+                match token {
+                    $($token => Ok($name::$variant),)+
+                    _ => {
+                        $crate::__private::validate_token(token)?;
+                        Ok($name::Unknown(token.into()))
+                    }
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<&$crate::BareItem> for $name {
+            type Error = &'static str;
+
+            fn try_from(bare_item: &$crate::BareItem) -> ::core::result::Result<Self, Self::Error> {
+                match bare_item.as_token() {
+                    Some(token) => token.parse(),
+                    None => Err("token_enum: bare item is not a token"),
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::BareItem {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(token) => $crate::BareItem::Token(token),
+                    value => $crate::BareItem::Token(value.as_str().into()),
+                }
+            }
+        }
+    };
+}
+
+/// Builds `Item` from a bare item and parameters, e.g. for tests and fixtures.
+///
+/// Bare item and parameter values are any expressions convertible into `BareItem`,
+/// parameter keys are `&str` or `String` expressions, followed by `=>`.
+/// As keys and values may be invalid and keys may repeat, evaluates to `Result<Item, &'static str>`.
+/// # Examples
+/// ```
+/// use sfv::{sfv_item, BareItem, SerializeValue};
+///
+/// let item = sfv_item!(BareItem::Token("foo".into()); "a" => 1, "b" => true).unwrap();
+/// assert_eq!(item.serialize_value().unwrap(), "foo;a=1;b");
+///
+/// assert!(sfv_item!(1; "Invalid" => 2).is_err());
+/// assert!(sfv_item!(1; "a" => 2, "a" => 3).is_err());
+/// ```
+#[macro_export]
+macro_rules! sfv_item {
+    ($bare_item:expr $(; $($key:expr => $value:expr),* $(,)?)?) => {
+        $crate::__private::item(
+            $crate::BareItem::from($bare_item),
+            $crate::__private::vec![$($((
+                $crate::__private::String::from($key),
+                $crate::BareItem::from($value),
+            )),*)?],
+        )
+    };
+}
+
+/// Builds `List` from members, i.e. expressions convertible into `ListEntry`, such as `Item` and `InnerList`.
+///
+/// Members are validated, so evaluates to `Result<List, &'static str>`.
+/// # Examples
+/// ```
+/// use sfv::{sfv_item, sfv_list, InnerList, SerializeValue};
+///
+/// # fn main() -> Result<(), &'static str> {
+/// let inner_list = InnerList::new(vec![sfv_item!(1)?, sfv_item!(2)?]);
+/// let list = sfv_list![sfv_item!(5; "a" => false)?, inner_list]?;
+/// assert_eq!(list.serialize_value()?, "5;a=?0, (1 2)");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sfv_list {
+    ($($member:expr),* $(,)?) => {
+        $crate::__private::list($crate::__private::vec![$($crate::ListEntry::from($member)),*])
+    };
+}
+
+/// Builds `Dictionary` from keys, i.e. `&str` or `String` expressions,
+/// followed by `=>` and members, i.e. expressions convertible into `ListEntry`.
+///
+/// Keys and members are validated and keys must be unique, so evaluates to `Result<Dictionary, &'static str>`.
+/// # Examples
+/// ```
+/// use sfv::{sfv_dict, sfv_item, BareItem, SerializeValue};
+///
+/// # fn main() -> Result<(), &'static str> {
+/// let dict = sfv_dict! {
+///     "a" => sfv_item!(true; "x" => 1)?,
+///     "b" => sfv_item!(BareItem::String("str".into()))?,
+/// }?;
+/// assert_eq!(dict.serialize_value()?, "a;x=1, b=\"str\"");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! sfv_dict {
+    ($($key:expr => $member:expr),* $(,)?) => {
+        $crate::__private::dict($crate::__private::vec![$((
+            $crate::__private::String::from($key),
+            $crate::ListEntry::from($member),
+        )),*])
+    };
+}
+
+// Functions called by `token_enum!`, `sfv_item!`, `sfv_list!` and `sfv_dict!`, re-exported from `__private`
+
+pub fn validate_token(token: &str) -> SFVResult<()> {
+    Serializer::validate_token(token)
+}
+
+pub fn item(bare_item: BareItem, params: Vec<(String, BareItem)>) -> SFVResult<Item> {
+    Serializer::validate_bare_item(&bare_item)?;
+    let mut item = Item::new(bare_item);
+    for (key, value) in params {
+        Serializer::validate_key(&key)?;
+        Serializer::validate_bare_item(&value)?;
+        if item.params.contains_key(&key) {
+            return Err("sfv_item: duplicate parameter key");
+        }
+        item.params.insert(key, value);
+    }
+    Ok(item)
+}
+
+pub fn list(members: Vec<ListEntry>) -> SFVResult<List> {
+    members.iter().try_for_each(validate_member)?;
+    Ok(members)
+}
+
+pub fn dict(members: Vec<(String, ListEntry)>) -> SFVResult<Dictionary> {
+    let mut dict = Dictionary::default();
+    for (key, member) in members {
+        Serializer::validate_key(&key)?;
+        validate_member(&member)?;
+        if dict.contains_key(&key) {
+            return Err("sfv_dict: duplicate key");
+        }
+        dict.insert(key, member);
+    }
+    Ok(dict)
+}
+
+fn validate_member(member: &ListEntry) -> SFVResult<()> {
+    match member {
+        ListEntry::Item(item) => validate_item(item),
+        ListEntry::InnerList(inner_list) => {
+            inner_list.items.iter().try_for_each(validate_item)?;
+            validate_params(&inner_list.params)
+        }
+    }
+}
+
+fn validate_item(item: &Item) -> SFVResult<()> {
+    Serializer::validate_bare_item(&item.bare_item)?;
+    validate_params(&item.params)
+}
+
+fn validate_params(params: &Parameters) -> SFVResult<()> {
+    for (key, value) in params {
+        Serializer::validate_key(key)?;
+        Serializer::validate_bare_item(value)?;
+    }
+    Ok(())
+}
@@ -1,106 +1,1397 @@
-use crate::utils;
+use crate::serializer::Serializer;
+use crate::text::FieldValue;
+use crate::utils::{self, CharOutput, Discard, Input, OffsetChars};
 use crate::{
     BareItem, Decimal, Dictionary, FromStr, InnerList, Item, List, ListEntry, Num, Parameters,
-    SFVResult,
+    SFVResult, MAX_INTEGER, MIN_INTEGER,
 };
-use std::iter::Peekable;
-use std::str::{from_utf8, Chars};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeSet,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::iter::Peekable;
+use core::ops::Range;
+use core::str::{from_utf8, Chars};
+use data_encoding::BASE64;
 
 /// Implements parsing logic for each structured field value type.
 pub trait ParseValue {
     /// This method should not be used for parsing input into structured field value.
     /// Use `Parser::parse_item`, `Parser::parse_list` or `Parsers::parse_dictionary` for that.
     fn parse(input_chars: &mut Peekable<Chars>) -> SFVResult<Self>
+    where
+        Self: Sized,
+    {
+        Self::parse_with_config(input_chars, &ParserConfig::default())
+    }
+
+    /// Same as `parse`, but follows specified `ParserConfig`.
+    /// This method should not be used for parsing input into structured field value.
+    /// Use `ParserConfig::parse_item`, `ParserConfig::parse_list` or `ParserConfig::parse_dictionary` for that.
+    fn parse_with_config(
+        input_chars: &mut Peekable<Chars>,
+        config: &ParserConfig,
+    ) -> SFVResult<Self>
+    where
+        Self: Sized;
+}
+
+/// If structured field value of List or Dictionary type is split into multiple lines,
+/// allows to parse more lines and merge them into already existing structure field value.
+pub trait ParseMore {
+    /// If structured field value is split across lines,
+    /// parses and merges next line into a single structured field value.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeValue, ParseMore};
+    ///
+    /// let mut list_field = Parser::parse_list("11, (12 13)".as_bytes()).unwrap();
+    /// list_field.parse_more("\"foo\",        \"bar\"".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(list_field.serialize_value().unwrap(), "11, (12 13), \"foo\", \"bar\"");
+    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()>
     where
         Self: Sized;
 }
 
-/// If structured field value of List or Dictionary type is split into multiple lines,
-/// allows to parse more lines and merge them into already existing structure field value.
-pub trait ParseMore {
-    /// If structured field value is split across lines,
-    /// parses and merges next line into a single structured field value.
+/// Returned by `DictionaryVisitor` callbacks to control whether parsing should go on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitFlow {
+    /// Continue parsing.
+    Continue,
+    /// Stop parsing, the rest of the input is neither parsed nor validated.
+    Stop,
+}
+
+/// Tells whether parsed input was already in the form it's serialized to. See `Parser::parse_item_checked`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Canonicality {
+    /// Serializing parsed value gives back the same input.
+    Canonical,
+    /// Input differs from serialized parsed value, e.g. `1.50` is serialized as `1.5` and `a;b=?1` as `a;b`.
+    NonCanonical,
+}
+
+/// Receives components of Dictionary structured field value as they are parsed,
+/// without building the `Dictionary` itself. See `Parser::parse_dictionary_visit`.
+///
+/// Every callback defaults to `VisitFlow::Continue`, so only relevant ones need to be implemented.
+/// Parameters are reported after the item or inner list they belong to.
+pub trait DictionaryVisitor {
+    /// Called for each dictionary member name, before its value.
+    fn on_key(&mut self, _key: &str) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    /// Called for each item, either a member value or a member of an inner list.
+    /// Members without value are reported as `BareItem::Boolean(true)`.
+    fn on_item(&mut self, _bare_item: &BareItem) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    /// Called when inner list opening parenthesis is parsed.
+    fn on_inner_list_start(&mut self) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    /// Called when inner list closing parenthesis is parsed, before inner list parameters.
+    fn on_inner_list_end(&mut self) -> VisitFlow {
+        VisitFlow::Continue
+    }
+
+    /// Called for each parameter of the last reported item or inner list.
+    fn on_parameter(&mut self, _key: &str, _value: &BareItem) -> VisitFlow {
+        VisitFlow::Continue
+    }
+}
+
+// Receives components of parsed values, so that building, visiting and validating structured field values
+// share the same parsing functions. Sinks which don't need some of the components use `()` for them.
+trait Sink {
+    type Key;
+    type BareItem;
+    type Params: Default;
+    type Item;
+    type InnerList;
+    type Entry: From<Self::Item> + From<Self::InnerList>;
+
+    fn key<I: Input>(&mut self, input_chars: &mut I) -> SFVResult<Self::Key>;
+
+    fn bare_item<I: Input>(
+        &mut self,
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<Self::BareItem>;
+
+    // Value of dictionary members and parameters without '='
+    fn true_value(&mut self) -> Self::BareItem;
+
+    fn parameter(
+        &mut self,
+        params: &mut Self::Params,
+        key: Self::Key,
+        value: Self::BareItem,
+    ) -> SFVResult<()>;
+
+    fn item(&mut self, bare_item: Self::BareItem, params: Self::Params) -> Self::Item;
+
+    fn inner_list(&mut self, items: Vec<Self::Item>, params: Self::Params) -> Self::InnerList;
+
+    // Called as soon as the component is parsed, before the components which follow it
+    fn on_dictionary_key(&mut self, _key: &Self::Key, _config: &ParserConfig) -> SFVResult<()> {
+        Ok(())
+    }
+
+    fn on_item(&mut self, _bare_item: &Self::BareItem) -> SFVResult<()> {
+        Ok(())
+    }
+
+    fn on_inner_list_start(&mut self) -> SFVResult<()> {
+        Ok(())
+    }
+
+    fn on_inner_list_end(&mut self) -> SFVResult<()> {
+        Ok(())
+    }
+}
+
+// Builds owned structured field values. With a scratch buffer, keys, strings, tokens and byte sequences
+// are scanned into the buffer first, so that the buffer grows instead of each value,
+// which is then allocated once with its exact length.
+#[derive(Default)]
+struct Build<'a> {
+    scratch: Option<&'a mut String>,
+}
+
+impl Build<'_> {
+    fn text<I: Input>(
+        &mut self,
+        input_chars: &mut I,
+        scan: impl FnOnce(&mut I, &mut String) -> SFVResult<()>,
+    ) -> SFVResult<String> {
+        match &mut self.scratch {
+            Some(scratch) => {
+                scratch.clear();
+                scan(input_chars, scratch)?;
+                Ok(scratch.as_str().to_owned())
+            }
+            None => {
+                let mut output = String::new();
+                scan(input_chars, &mut output)?;
+                Ok(output)
+            }
+        }
+    }
+
+    fn byte_sequence<I: Input>(
+        &mut self,
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<Vec<u8>> {
+        let mut b64_content = String::new();
+        let b64_content = match &mut self.scratch {
+            Some(scratch) => {
+                scratch.clear();
+                scratch
+            }
+            None => &mut b64_content,
+        };
+        Parser::scan_byte_sequence(input_chars, config, b64_content)?;
+
+        let mut output = Vec::new();
+        Parser::decode_byte_sequence(b64_content, &mut output)?;
+        Ok(output)
+    }
+}
+
+impl Sink for Build<'_> {
+    type Key = String;
+    type BareItem = BareItem;
+    type Params = Parameters;
+    type Item = Item;
+    type InnerList = InnerList;
+    type Entry = ListEntry;
+
+    fn key<I: Input>(&mut self, input_chars: &mut I) -> SFVResult<String> {
+        self.text(input_chars, Parser::scan_key)
+    }
+
+    fn bare_item<I: Input>(
+        &mut self,
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<BareItem> {
+        // https://httpwg.org/specs/rfc8941.html#parse-bare-item
+        if input_chars.peek().is_none() {
+            return Err("parse_bare_item: empty item");
+        }
+
+        match input_chars.peek() {
+            Some(&'?') => Ok(BareItem::Boolean(Parser::parse_bool(input_chars)?)),
+            Some(&'"') => Ok(BareItem::String(
+                self.text(input_chars, Parser::scan_string)?,
+            )),
+            Some(&':') => Ok(BareItem::ByteSeq(self.byte_sequence(input_chars, config)?)),
+            Some(&c) if c == '*' || c.is_ascii_alphabetic() => {
+                Ok(BareItem::Token(self.text(input_chars, Parser::scan_token)?))
+            }
+            Some(&c) if c == '-' || c.is_ascii_digit() => {
+                match Parser::parse_number(input_chars, config)? {
+                    Num::Decimal(val) => Ok(BareItem::Decimal(val)),
+                    Num::Integer(val) => Ok(BareItem::Integer(val)),
+                }
+            }
+            _ => Err("parse_bare_item: item type can't be identified"),
+        }
+    }
+
+    fn true_value(&mut self) -> BareItem {
+        BareItem::Boolean(true)
+    }
+
+    fn parameter(
+        &mut self,
+        params: &mut Parameters,
+        key: String,
+        value: BareItem,
+    ) -> SFVResult<()> {
+        // If parameters already contains a name param_name (comparing character-for-character), overwrite its value.
+        // Note that when duplicate Parameter keys are encountered, this has the effect of ignoring all but the last instance.
+        params.insert(key, value);
+        Ok(())
+    }
+
+    fn item(&mut self, bare_item: BareItem, params: Parameters) -> Item {
+        Item { bare_item, params }
+    }
+
+    fn inner_list(&mut self, items: Vec<Item>, params: Parameters) -> InnerList {
+        InnerList { items, params }
+    }
+}
+
+// Keeps Dictionary keys parsed so far, so that sinks which don't build a `Dictionary` can detect duplicate keys
+#[derive(Default)]
+struct SeenKeys {
+    keys: BTreeSet<String>,
+    duplicate: bool,
+}
+
+impl SeenKeys {
+    fn add(&mut self, key: &str) {
+        if self.keys.contains(key) {
+            self.duplicate = true;
+        } else {
+            self.keys.insert(key.to_owned());
+        }
+    }
+
+    // Called once the member is parsed, so that duplicate keys are reported at the same point as when building
+    fn check_member(&mut self) -> SFVResult<()> {
+        if core::mem::take(&mut self.duplicate) {
+            return Err("parse_dict: duplicate key");
+        }
+        Ok(())
+    }
+}
+
+// Reports Dictionary components to a visitor. Keys are parsed into a reused buffer
+// and visitor asking to stop is reported as an error, which parse_dictionary_visit turns back into `Ok(())`.
+struct Visit<'a, V> {
+    visitor: &'a mut V,
+    key: String,
+    seen_keys: SeenKeys,
+    stopped: bool,
+}
+
+impl<'a, V: DictionaryVisitor> Visit<'a, V> {
+    fn new(visitor: &'a mut V) -> Self {
+        Visit {
+            visitor,
+            key: String::new(),
+            seen_keys: SeenKeys::default(),
+            stopped: false,
+        }
+    }
+
+    fn flow(&mut self, flow: VisitFlow) -> SFVResult<()> {
+        if flow == VisitFlow::Stop {
+            self.stopped = true;
+            return Err("parse_dictionary_visit: stopped by visitor");
+        }
+        Ok(())
+    }
+}
+
+impl<V: DictionaryVisitor> Sink for Visit<'_, V> {
+    type Key = ();
+    type BareItem = BareItem;
+    type Params = ();
+    type Item = ();
+    type InnerList = ();
+    type Entry = ();
+
+    fn key<I: Input>(&mut self, input_chars: &mut I) -> SFVResult<()> {
+        self.key.clear();
+        Parser::scan_key(input_chars, &mut self.key)
+    }
+
+    fn bare_item<I: Input>(
+        &mut self,
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<BareItem> {
+        Parser::parse_bare_item_with(input_chars, config)
+    }
+
+    fn true_value(&mut self) -> BareItem {
+        BareItem::Boolean(true)
+    }
+
+    fn parameter(&mut self, _params: &mut (), _key: (), value: BareItem) -> SFVResult<()> {
+        let flow = self.visitor.on_parameter(&self.key, &value);
+        self.flow(flow)
+    }
+
+    fn item(&mut self, _bare_item: BareItem, _params: ()) {}
+
+    fn inner_list(&mut self, _items: Vec<()>, _params: ()) {}
+
+    fn on_dictionary_key(&mut self, _key: &(), config: &ParserConfig) -> SFVResult<()> {
+        let flow = self.visitor.on_key(&self.key);
+        self.flow(flow)?;
+        if config.reject_duplicate_keys {
+            self.seen_keys.add(&self.key);
+        }
+        Ok(())
+    }
+
+    fn on_item(&mut self, bare_item: &BareItem) -> SFVResult<()> {
+        let flow = self.visitor.on_item(bare_item);
+        self.flow(flow)
+    }
+
+    fn on_inner_list_start(&mut self) -> SFVResult<()> {
+        let flow = self.visitor.on_inner_list_start();
+        self.flow(flow)
+    }
+
+    fn on_inner_list_end(&mut self) -> SFVResult<()> {
+        let flow = self.visitor.on_inner_list_end();
+        self.flow(flow)
+    }
+}
+
+// Only checks the input, scanned characters are discarded so that nothing is allocated.
+// Keys are kept only when duplicate keys have to be detected.
+struct Validate {
+    track_keys: bool,
+    key: String,
+    seen_keys: SeenKeys,
+}
+
+impl Validate {
+    fn new(config: &ParserConfig) -> Self {
+        Validate {
+            track_keys: config.reject_duplicate_keys,
+            key: String::new(),
+            seen_keys: SeenKeys::default(),
+        }
+    }
+}
+
+impl Sink for Validate {
+    type Key = ();
+    type BareItem = ();
+    type Params = ();
+    type Item = ();
+    type InnerList = ();
+    type Entry = ();
+
+    fn key<I: Input>(&mut self, input_chars: &mut I) -> SFVResult<()> {
+        if self.track_keys {
+            self.key.clear();
+            Parser::scan_key(input_chars, &mut self.key)
+        } else {
+            Parser::scan_key(input_chars, &mut Discard)
+        }
+    }
+
+    fn bare_item<I: Input>(&mut self, input_chars: &mut I, config: &ParserConfig) -> SFVResult<()> {
+        Parser::scan_bare_item(input_chars, config)
+    }
+
+    fn true_value(&mut self) {}
+
+    fn parameter(&mut self, _params: &mut (), _key: (), _value: ()) -> SFVResult<()> {
+        Ok(())
+    }
+
+    fn item(&mut self, _bare_item: (), _params: ()) {}
+
+    fn inner_list(&mut self, _items: Vec<()>, _params: ()) {}
+
+    fn on_dictionary_key(&mut self, _key: &(), _config: &ParserConfig) -> SFVResult<()> {
+        if self.track_keys {
+            self.seen_keys.add(&self.key);
+        }
+        Ok(())
+    }
+}
+
+impl ParseValue for BareItem {
+    fn parse_with_config(
+        input_chars: &mut Peekable<Chars>,
+        config: &ParserConfig,
+    ) -> SFVResult<BareItem> {
+        Parser::parse_bare_item_with(input_chars, config)
+    }
+}
+
+impl ParseValue for Item {
+    fn parse_with_config(
+        input_chars: &mut Peekable<Chars>,
+        config: &ParserConfig,
+    ) -> SFVResult<Item> {
+        Parser::parse_item_with(input_chars, config)
+    }
+}
+
+impl ParseValue for List {
+    fn parse_with_config(
+        input_chars: &mut Peekable<Chars>,
+        config: &ParserConfig,
+    ) -> SFVResult<List> {
+        Parser::parse_list_with(input_chars, config, &mut Build::default())
+    }
+}
+
+impl ParseValue for Dictionary {
+    fn parse_with_config(
+        input_chars: &mut Peekable<Chars>,
+        config: &ParserConfig,
+    ) -> SFVResult<Dictionary> {
+        Parser::parse_dictionary_with(input_chars, config, &mut Build::default())
+    }
+}
+
+impl ParseMore for List {
+    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()> {
+        let parsed_list = Parser::parse_list(input_bytes)?;
+        self.extend(parsed_list);
+        Ok(())
+    }
+}
+
+impl ParseMore for Dictionary {
+    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()> {
+        let parsed_dict = Parser::parse_dictionary(input_bytes)?;
+        self.extend(parsed_dict);
+        Ok(())
+    }
+}
+
+/// Configures optional deviations from parsing algorithms defined in RFC 8941.
+/// Default configuration strictly follows RFC 8941.
+///
+/// Note that some inputs are rejected regardless of configuration,
+/// e.g. numbers with leading `+` sign are forbidden by RFC 8941 grammar.
+/// # Examples
+/// ```
+/// # use sfv::{Parser, ParserConfig};
+/// let config = ParserConfig {
+///     strict_numbers: true,
+///     ..ParserConfig::default()
+/// };
+/// assert!(config.parse_item("05".as_bytes()).is_err());
+/// assert!(Parser::parse_item("05".as_bytes()).is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParserConfig {
+    /// Rejects numbers which are not in their canonical serialized form, i.e.
+    /// integer component with leading zeros (`05`, `-00.5`) and
+    /// fractional component with trailing zeros (`1.230`, `1.00`, while `1.0` is accepted).
+    /// RFC 8941 allows both of those forms when parsing.
+    pub strict_numbers: bool,
+    /// Keeps the number of fractional digits of parsed decimals, so that `2.50` is serialized back as `2.50`
+    /// with `SerializeOptions::preserve_decimal_scale`.
+    /// By default parsed decimals are normalized and `2.50` is serialized as `2.5`, which is its canonical form.
+    /// Fractional component is limited to 3 digits either way, and zero fractional component is serialized as `.0`.
+    pub preserve_decimal_scale: bool,
+    /// Tolerates whitespace (spaces and tabs) which RFC 8941 doesn't allow, as emitted by some non-conformant senders:
+    /// - before and after `=` in dictionary members and parameters, e.g. `a = 1`,
+    /// - before `;` and tabs after `;` in parameters, e.g. `1 ;\ta`.
+    ///
+    /// Parsed value is the same as for the conformant input, so it's serialized in canonical form.
+    pub lenient_whitespace: bool,
+    /// Rejects byte sequences which decoded length exceeds the limit, e.g. `Some(1024)`.
+    /// Decoded length is computed from the base64 content length, so oversized byte sequences are rejected before decoding.
+    /// There's no limit by default.
+    pub max_byte_seq_len: Option<usize>,
+    /// Accepts a single trailing comma after the last `List` or `Dictionary` member, e.g. `a=1, b=2,`,
+    /// as emitted by some non-conformant senders.
+    /// Empty members, e.g. `a=1,,b=2`, are rejected regardless.
+    pub allow_trailing_comma: bool,
+    /// Rejects `Dictionary` which has several members with the same key, e.g. `a=1, a=2`,
+    /// for applications which treat such input as malformed.
+    /// By default the last member wins, as RFC 8941 requires.
+    pub reject_duplicate_keys: bool,
+}
+
+impl ParserConfig {
+    /// Parses input into structured field value of Dictionary type
+    pub fn parse_dictionary(&self, input_bytes: &[u8]) -> SFVResult<Dictionary> {
+        Parser::parse::<Dictionary>(input_bytes, self)
+    }
+
+    /// Parses input into structured field value of List type
+    pub fn parse_list(&self, input_bytes: &[u8]) -> SFVResult<List> {
+        Parser::parse::<List>(input_bytes, self)
+    }
+
+    /// Parses input into structured field value of Item type
+    pub fn parse_item(&self, input_bytes: &[u8]) -> SFVResult<Item> {
+        Parser::parse::<Item>(input_bytes, self)
+    }
+
+    /// Parses input into a single `BareItem` without parameters
+    pub fn parse_bare_item(&self, input_bytes: &[u8]) -> SFVResult<BareItem> {
+        Parser::parse::<BareItem>(input_bytes, self)
+    }
+
+    /// Parses input as structured field value of Dictionary type, reporting its components to `visitor`.
+    /// See `Parser::parse_dictionary_visit`.
+    pub fn parse_dictionary_visit<V: DictionaryVisitor>(
+        &self,
+        input_bytes: &[u8],
+        visitor: &mut V,
+    ) -> SFVResult<()> {
+        let mut sink = Visit::new(visitor);
+        let result = Parser::parse_input(input_bytes, |input_chars| {
+            Parser::parse_dictionary_members(input_chars, self, &mut sink, |sink, _, _| {
+                sink.seen_keys.check_member()
+            })
+        });
+        if sink.stopped {
+            return Ok(());
+        }
+        result
+    }
+
+    /// Checks whether input is a valid structured field value of Dictionary type without building it.
+    /// Accepts and rejects the same inputs as `parse_dictionary`.
+    /// Doesn't allocate, unless `reject_duplicate_keys` is set, as keys have to be kept to detect duplicates.
+    pub fn validate_dictionary(&self, input_bytes: &[u8]) -> SFVResult<()> {
+        let mut sink = Validate::new(self);
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::parse_dictionary_members(input_chars, self, &mut sink, |sink, _, _| {
+                sink.seen_keys.check_member()
+            })
+        })
+    }
+
+    /// Checks whether input is a valid structured field value of List type without building it.
+    /// Accepts and rejects the same inputs as `parse_list`.
+    /// Doesn't allocate.
+    pub fn validate_list(&self, input_bytes: &[u8]) -> SFVResult<()> {
+        let mut sink = Validate::new(self);
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::parse_list_members(input_chars, self, |input_chars| {
+                Parser::list_entry(input_chars, self, &mut sink)
+            })
+        })
+    }
+
+    /// Checks whether input is a valid structured field value of Item type without building it.
+    /// Accepts and rejects the same inputs as `parse_item`.
+    /// Doesn't allocate.
+    pub fn validate_item(&self, input_bytes: &[u8]) -> SFVResult<()> {
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::item(input_chars, self, &mut Validate::new(self))
+        })
+    }
+}
+
+/// Parser which keeps a scratch buffer between calls, for parsing many structured field values in a row,
+/// e.g. all the fields of a request.
+///
+/// Keys, strings, tokens and byte sequences are scanned into the scratch buffer first,
+/// so that parsed values are allocated once with their exact length rather than grown character by character,
+/// and base64 content of byte sequences doesn't need a buffer of its own.
+/// Parsed values are the same as the ones of `Parser` and `ParserConfig`.
+/// # Examples
+/// ```
+/// # use sfv::{Parser, ReusableParser};
+/// let mut parser = ReusableParser::new();
+/// for input in ["\"foo\", bar;a=1", "(1 2);b=:YWJj:"].iter() {
+///     assert_eq!(parser.parse_list(input.as_bytes()), Parser::parse_list(input.as_bytes()));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ReusableParser {
+    config: ParserConfig,
+    scratch: String,
+}
+
+impl ReusableParser {
+    /// Creates parser with the default `ParserConfig`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates parser with the given `ParserConfig`.
+    pub fn with_config(config: ParserConfig) -> Self {
+        ReusableParser {
+            config,
+            scratch: String::new(),
+        }
+    }
+
+    /// Parses input into structured field value of Dictionary type
+    pub fn parse_dictionary(&mut self, input_bytes: &[u8]) -> SFVResult<Dictionary> {
+        let config = &self.config;
+        let mut sink = Build {
+            scratch: Some(&mut self.scratch),
+        };
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::parse_dictionary_with(input_chars, config, &mut sink)
+        })
+    }
+
+    /// Parses input into structured field value of List type
+    pub fn parse_list(&mut self, input_bytes: &[u8]) -> SFVResult<List> {
+        let config = &self.config;
+        let mut sink = Build {
+            scratch: Some(&mut self.scratch),
+        };
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::parse_list_with(input_chars, config, &mut sink)
+        })
+    }
+
+    /// Parses input into structured field value of Item type
+    pub fn parse_item(&mut self, input_bytes: &[u8]) -> SFVResult<Item> {
+        let config = &self.config;
+        let mut sink = Build {
+            scratch: Some(&mut self.scratch),
+        };
+        Parser::parse_input(input_bytes, |input_chars| {
+            Parser::item(input_chars, config, &mut sink)
+        })
+    }
+}
+
+/// Exposes methods for parsing input into structured field value.
+pub struct Parser;
+
+impl Parser {
+    /// Parses input into structured field value of Dictionary type.
+    /// Empty input, e.g. of a missing field, is parsed into an empty `Dictionary`.
+    /// Note that serializing an empty `Dictionary` fails, see `SerializeValue::serialize_value_or_empty`.
+    pub fn parse_dictionary(input_bytes: &[u8]) -> SFVResult<Dictionary> {
+        Self::parse::<Dictionary>(input_bytes, &ParserConfig::default())
+    }
+
+    /// Parses input into `Dictionary` members in their order, keeping members with duplicate keys.
+    /// Unlike `parse_dictionary`, where the last of members with the same key wins as RFC 8941 requires,
+    /// allows to detect conflicting values sent for the same key, e.g. when auditing requests.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let members = Parser::parse_dictionary_multi("a=1, b, a=2".as_bytes()).unwrap();
+    /// let keys: Vec<&str> = members.iter().map(|(key, _)| key.as_str()).collect();
+    /// assert_eq!(keys, ["a", "b", "a"]);
+    /// assert_eq!(members[2].1.as_item().unwrap().bare_item, BareItem::Integer(2));
+    /// ```
+    pub fn parse_dictionary_multi(input_bytes: &[u8]) -> SFVResult<Vec<(String, ListEntry)>> {
+        let mut input_chars = Self::input_chars(input_bytes)?;
+        utils::consume_sp_chars(&mut input_chars);
+
+        let mut members = Vec::new();
+        Self::parse_dictionary_members(
+            &mut input_chars,
+            &ParserConfig::default(),
+            &mut Build::default(),
+            |_, key, member| {
+                members.push((key, member));
+                Ok(())
+            },
+        )?;
+
+        utils::consume_sp_chars(&mut input_chars);
+
+        if input_chars.next().is_some() {
+            return Err("parse: trailing characters after parsed value");
+        };
+        Ok(members)
+    }
+
+    /// Parses input into structured field value of List type.
+    /// Empty input, e.g. of a missing field, is parsed into an empty `List`.
+    /// Note that serializing an empty `List` fails, see `SerializeValue::serialize_value_or_empty`.
+    pub fn parse_list(input_bytes: &[u8]) -> SFVResult<List> {
+        Self::parse::<List>(input_bytes, &ParserConfig::default())
+    }
+
+    /// Parses input into structured field value of Item type.
+    /// Unlike `List` and `Dictionary`, `Item` can't be empty, so empty input is an error.
+    pub fn parse_item(input_bytes: &[u8]) -> SFVResult<Item> {
+        Self::parse::<Item>(input_bytes, &ParserConfig::default())
+    }
+
+    /// Parses input into a single `BareItem`.
+    /// Useful for fields which grammar embeds a bare item without parameters.
+    /// Input must not contain anything but the bare item and surrounding spaces.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// assert_eq!(Parser::parse_bare_item("?1".as_bytes()).unwrap(), BareItem::Boolean(true));
+    /// assert!(Parser::parse_bare_item("foo;a=1".as_bytes()).is_err());
+    /// ```
+    pub fn parse_bare_item(input_bytes: &[u8]) -> SFVResult<BareItem> {
+        Self::parse::<BareItem>(input_bytes, &ParserConfig::default())
+    }
+
+    /// Parses input into structured field value of whichever type accepts it,
+    /// for fields which type is not known in advance.
+    ///
+    /// Grammars of structured field types overlap, e.g. `a` is a valid Item (a token),
+    /// List (of one token) and Dictionary (with member `a` of `true` value).
+    /// Input is interpreted as the narrowest type that accepts it, trying Item, then List, then Dictionary.
+    /// So input is interpreted as Dictionary only if some member has a value, e.g. `a=1`, and
+    /// the other members are interpreted as tokens otherwise.
+    /// Empty input is not a valid Item, so it's parsed as an empty List.
+    /// If input isn't valid for any type, the error of parsing it as Dictionary is returned.
+    /// If the type of the field is known, use `text::parse_field` or type-specific parsing methods instead.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, text::FieldValue};
+    /// assert!(matches!(Parser::parse_auto("a;x=1".as_bytes()), Ok(FieldValue::Item(_))));
+    /// assert!(matches!(Parser::parse_auto("a, b".as_bytes()), Ok(FieldValue::List(_))));
+    /// assert!(matches!(Parser::parse_auto("a, b=1".as_bytes()), Ok(FieldValue::Dictionary(_))));
+    /// assert_eq!(Parser::parse_auto("".as_bytes()), Ok(FieldValue::List(vec![])));
+    /// ```
+    pub fn parse_auto(input_bytes: &[u8]) -> SFVResult<FieldValue> {
+        if let Ok(item) = Self::parse_item(input_bytes) {
+            return Ok(FieldValue::Item(item));
+        }
+        if let Ok(list) = Self::parse_list(input_bytes) {
+            return Ok(FieldValue::List(list));
+        }
+        Self::parse_dictionary(input_bytes).map(FieldValue::Dictionary)
+    }
+
+    /// Parses input into structured field value of Item type,
+    /// returning its `BareItem` and `Parameters` separately.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let (bare_item, params) = Parser::parse_item_parts("5;a=1".as_bytes()).unwrap();
+    /// assert_eq!(bare_item, BareItem::Integer(5));
+    /// assert_eq!(params.get("a"), Some(&BareItem::Integer(1)));
+    /// ```
+    pub fn parse_item_parts(input_bytes: &[u8]) -> SFVResult<(BareItem, Parameters)> {
+        let item = Self::parse_item(input_bytes)?;
+        Ok((item.bare_item, item.params))
+    }
+
+    /// Parses input into structured field value of Item type,
+    /// also telling whether input is in canonical form, i.e. the same as serialized `Item`.
+    /// Useful when non-canonical input must be detected rather than silently normalized,
+    /// e.g. when verifying signatures over field values.
+    /// Leading and trailing spaces are not part of the field value, so they're ignored.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Canonicality, Parser};
+    /// let (_, canonicality) = Parser::parse_item_checked("1.5;a".as_bytes()).unwrap();
+    /// assert_eq!(canonicality, Canonicality::Canonical);
+    ///
+    /// let (item, canonicality) = Parser::parse_item_checked("1.50;a=?1".as_bytes()).unwrap();
+    /// assert_eq!(canonicality, Canonicality::NonCanonical);
+    /// assert_eq!(item, Parser::parse_item("1.5;a".as_bytes()).unwrap());
+    /// ```
+    pub fn parse_item_checked(input_bytes: &[u8]) -> SFVResult<(Item, Canonicality)> {
+        let item = Self::parse_item(input_bytes)?;
+
+        let mut serialized = String::new();
+        Serializer::serialize_item(&item, &mut serialized)?;
+        let trimmed_input = Self::trim_sp(input_bytes);
+
+        let canonicality = if serialized.as_bytes() == trimmed_input {
+            Canonicality::Canonical
+        } else {
+            Canonicality::NonCanonical
+        };
+        Ok((item, canonicality))
+    }
+
+    fn trim_sp(input_bytes: &[u8]) -> &[u8] {
+        let start = input_bytes
+            .iter()
+            .position(|c| c != &b' ')
+            .unwrap_or(input_bytes.len());
+        let end = input_bytes
+            .iter()
+            .rposition(|c| c != &b' ')
+            .map_or(start, |position| position + 1);
+        &input_bytes[start..end]
+    }
+
+    /// Parses input consisting of a single `String` bare item, without parameters,
+    /// returning its content borrowed from input when the string has no escaped characters.
+    /// Only for fields which value is a lone string: input must not contain anything but the string
+    /// and surrounding spaces. Other parsing functions are not affected and always build owned strings.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// # use std::borrow::Cow;
+    /// let value = Parser::parse_bare_string(r#""hello world""#.as_bytes()).unwrap();
+    /// assert!(matches!(value, Cow::Borrowed("hello world")));
+    ///
+    /// let value = Parser::parse_bare_string(r#""say \"hi\"""#.as_bytes()).unwrap();
+    /// assert_eq!(value, r#"say "hi""#);
+    /// assert!(matches!(value, Cow::Owned(_)));
+    /// ```
+    pub fn parse_bare_string(input_bytes: &[u8]) -> SFVResult<Cow<'_, str>> {
+        let input = Self::input_str(input_bytes)?.trim_matches(' ');
+
+        // Fast path: string without escapes is the input between quotes as is
+        if let Some(content) = input
+            .strip_prefix('\"')
+            .and_then(|rest| rest.strip_suffix('\"'))
+        {
+            if content
+                .chars()
+                .all(|c| c != '\\' && c != '\"' && (' '..='~').contains(&c))
+            {
+                return Ok(Cow::Borrowed(content));
+            }
+        }
+
+        // Otherwise parse string the usual way, which unescapes it or reports an error
+        let mut input_chars = input.chars().peekable();
+        let output = Self::parse_string(&mut input_chars)?;
+        if input_chars.next().is_some() {
+            return Err("parse: trailing characters after parsed value");
+        };
+        Ok(Cow::Owned(output))
+    }
+
+    /// Parses input into structured field value of Item type,
+    /// which bare item is expected to be an `Integer`.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let item = Parser::parse_integer_item("42;a".as_bytes()).unwrap();
+    /// assert_eq!(item.bare_item, BareItem::Integer(42));
+    /// assert_eq!(
+    ///     Parser::parse_integer_item("4.2".as_bytes()),
+    ///     Err("parse: expected integer, found decimal")
+    /// );
+    /// ```
+    pub fn parse_integer_item(input_bytes: &[u8]) -> SFVResult<Item> {
+        let item = Self::parse_item(input_bytes)?;
+        match item.bare_item {
+            BareItem::Integer(_) => Ok(item),
+            ref other => Err(Self::type_mismatch("integer", other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type,
+    /// which bare item is expected to be a `String`.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let item = Parser::parse_string_item("\"foo\"".as_bytes()).unwrap();
+    /// assert_eq!(item.bare_item, BareItem::String("foo".into()));
+    /// assert_eq!(
+    ///     Parser::parse_string_item("foo".as_bytes()),
+    ///     Err("parse: expected string, found token")
+    /// );
+    /// ```
+    pub fn parse_string_item(input_bytes: &[u8]) -> SFVResult<Item> {
+        let item = Self::parse_item(input_bytes)?;
+        match item.bare_item {
+            BareItem::String(_) => Ok(item),
+            ref other => Err(Self::type_mismatch("string", other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type,
+    /// which bare item is expected to be a `Token`.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Parser};
+    /// let item = Parser::parse_token_item("foo;a=1".as_bytes()).unwrap();
+    /// assert_eq!(item.bare_item, BareItem::Token("foo".into()));
+    /// assert_eq!(
+    ///     Parser::parse_token_item("?1".as_bytes()),
+    ///     Err("parse: expected token, found boolean")
+    /// );
+    /// ```
+    pub fn parse_token_item(input_bytes: &[u8]) -> SFVResult<Item> {
+        let item = Self::parse_item(input_bytes)?;
+        match item.bare_item {
+            BareItem::Token(_) => Ok(item),
+            ref other => Err(Self::type_mismatch("token", other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type and returns its `Integer` value,
+    /// e.g. for fields like `Retry-After`. `Parameters` of the item are ignored.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert_eq!(Parser::parse_integer("120".as_bytes()), Ok(120));
+    /// assert_eq!(Parser::parse_integer("-7;unit=s".as_bytes()), Ok(-7));
+    /// assert_eq!(
+    ///     Parser::parse_integer("\"120\"".as_bytes()),
+    ///     Err("parse: expected integer, found string")
+    /// );
+    /// ```
+    pub fn parse_integer(input_bytes: &[u8]) -> SFVResult<i64> {
+        match Self::parse_item(input_bytes)?.bare_item {
+            BareItem::Integer(value) => Ok(value),
+            other => Err(Self::type_mismatch("integer", &other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type and returns its `Decimal` value.
+    /// `Integer` is not accepted, as RFC 8941 distinguishes the two.
+    /// `Parameters` of the item are ignored.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Decimal, FromStr, Parser};
+    /// assert_eq!(Parser::parse_decimal("0.25".as_bytes()), Ok(Decimal::from_str("0.25").unwrap()));
+    /// assert_eq!(
+    ///     Parser::parse_decimal("1".as_bytes()),
+    ///     Err("parse: expected decimal, found integer")
+    /// );
+    /// ```
+    pub fn parse_decimal(input_bytes: &[u8]) -> SFVResult<Decimal> {
+        match Self::parse_item(input_bytes)?.bare_item {
+            BareItem::Decimal(value) => Ok(value),
+            other => Err(Self::type_mismatch("decimal", &other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type and returns its `Boolean` value.
+    /// `Parameters` of the item are ignored.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert_eq!(Parser::parse_boolean("?1".as_bytes()), Ok(true));
+    /// assert_eq!(
+    ///     Parser::parse_boolean("1".as_bytes()),
+    ///     Err("parse: expected boolean, found integer")
+    /// );
+    /// ```
+    pub fn parse_boolean(input_bytes: &[u8]) -> SFVResult<bool> {
+        match Self::parse_item(input_bytes)?.bare_item {
+            BareItem::Boolean(value) => Ok(value),
+            other => Err(Self::type_mismatch("boolean", &other)),
+        }
+    }
+
+    /// Parses input into structured field value of Item type and returns its `Token` value.
+    /// `Parameters` of the item are ignored.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert_eq!(Parser::parse_token_string("gzip;q=1".as_bytes()), Ok("gzip".to_owned()));
+    /// assert_eq!(
+    ///     Parser::parse_token_string("\"gzip\"".as_bytes()),
+    ///     Err("parse: expected token, found string")
+    /// );
+    /// ```
+    pub fn parse_token_string(input_bytes: &[u8]) -> SFVResult<String> {
+        match Self::parse_item(input_bytes)?.bare_item {
+            BareItem::Token(value) => Ok(value),
+            other => Err(Self::type_mismatch("token", &other)),
+        }
+    }
+
+    // Error for bare item of unexpected type, naming both the expected type and the found one (see `BareItem::type_name`).
+    // Errors are static strings, so messages are listed for each pair of types.
+    fn type_mismatch(expected: &'static str, found: &BareItem) -> &'static str {
+        macro_rules! messages {
+            ($($expected:literal => [$($found:literal),*]),*) => {
+                match (expected, found.type_name()) {
+                    $($(
+                        ($expected, $found) => concat!("parse: expected ", $expected, ", found ", $found),
+                    )*)*
+                    _ => "parse: unexpected bare item type",
+                }
+            };
+        }
+        messages! {
+            "integer" => ["decimal", "string", "token", "byte-sequence", "boolean"],
+            "decimal" => ["integer", "string", "token", "byte-sequence", "boolean"],
+            "string" => ["integer", "decimal", "token", "byte-sequence", "boolean"],
+            "token" => ["integer", "decimal", "string", "byte-sequence", "boolean"],
+            "boolean" => ["integer", "decimal", "string", "token", "byte-sequence"]
+        }
+    }
+
+    /// Returns `ParserConfig` which rejects numbers not in their canonical form.
+    /// See `ParserConfig::strict_numbers`.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert!(Parser::strict().parse_item("1.50".as_bytes()).is_err());
+    /// assert!(Parser::strict().parse_item("1.5".as_bytes()).is_ok());
+    /// ```
+    pub fn strict() -> ParserConfig {
+        ParserConfig {
+            strict_numbers: true,
+            ..ParserConfig::default()
+        }
+    }
+
+    /// Returns `ParserConfig` which tolerates whitespace not allowed by RFC 8941
+    /// around `=` and before `;`. See `ParserConfig::lenient_whitespace` for the exact list of deviations.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let dict = Parser::lenient().parse_dictionary("a = 1 , b = 2".as_bytes()).unwrap();
+    /// assert_eq!(dict.serialize_value().unwrap(), "a=1, b=2");
+    /// assert!(Parser::parse_dictionary("a = 1 , b = 2".as_bytes()).is_err());
+    /// ```
+    pub fn lenient() -> ParserConfig {
+        ParserConfig {
+            lenient_whitespace: true,
+            ..ParserConfig::default()
+        }
+    }
+
+    /// Parses structured field value of List type split across multiple field lines.
+    /// Lines are combined with `", "` before parsing, empty lines are skipped.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    ///
+    /// let lines: &[&[u8]] = &[b"\"a\", \"b\"", b"", b"(1 2)"];
+    /// let list_field = Parser::parse_list_lines(lines).unwrap();
+    ///
+    /// assert_eq!(list_field.serialize_value().unwrap(), "\"a\", \"b\", (1 2)");
+    /// ```
+    pub fn parse_list_lines(lines: &[&[u8]]) -> SFVResult<List> {
+        // https://httpwg.org/specs/rfc8941.html#text-parse
+        // Field lines are combined according to HTTP field combination rules
+        let mut input_bytes = Vec::new();
+        for line in lines.iter().filter(|line| !Self::is_empty_line(line)) {
+            if !input_bytes.is_empty() {
+                input_bytes.extend_from_slice(b", ");
+            }
+            input_bytes.extend_from_slice(line);
+        }
+        Self::parse_list(&input_bytes)
+    }
+
+    fn is_empty_line(line: &[u8]) -> bool {
+        line.iter().all(|c| c == &b' ' || c == &b'\t')
+    }
+
+    /// Parses input as structured field value of Dictionary type,
+    /// reporting its components to `visitor` instead of building a `Dictionary`.
+    /// Useful when only a small part of the field is of interest,
+    /// e.g. to check whether a member is present.
+    ///
+    /// If any callback returns `VisitFlow::Stop`, parsing ends with `Ok(())`
+    /// and the rest of the input is not validated.
     /// # Examples
     /// ```
-    /// # use sfv::{Parser, SerializeValue, ParseMore};
+    /// # use sfv::{DictionaryVisitor, Parser, VisitFlow};
+    /// struct HasKey<'a> {
+    ///     key: &'a str,
+    ///     found: bool,
+    /// }
     ///
-    /// let mut list_field = Parser::parse_list("11, (12 13)".as_bytes()).unwrap();
-    /// list_field.parse_more("\"foo\",        \"bar\"".as_bytes()).unwrap();
+    /// impl DictionaryVisitor for HasKey<'_> {
+    ///     fn on_key(&mut self, key: &str) -> VisitFlow {
+    ///         self.found = key == self.key;
+    ///         if self.found { VisitFlow::Stop } else { VisitFlow::Continue }
+    ///     }
+    /// }
     ///
-    /// assert_eq!(list_field.serialize_value().unwrap(), "11, (12 13), \"foo\", \"bar\"");
-    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()>
-    where
-        Self: Sized;
-}
+    /// let mut visitor = HasKey { key: "b", found: false };
+    /// Parser::parse_dictionary_visit("a=(1 2), b=?0, c".as_bytes(), &mut visitor).unwrap();
+    /// assert!(visitor.found);
+    /// ```
+    pub fn parse_dictionary_visit<V: DictionaryVisitor>(
+        input_bytes: &[u8],
+        visitor: &mut V,
+    ) -> SFVResult<()> {
+        ParserConfig::default().parse_dictionary_visit(input_bytes, visitor)
+    }
 
-impl ParseValue for Item {
-    fn parse(input_chars: &mut Peekable<Chars>) -> SFVResult<Item> {
-        // https://httpwg.org/specs/rfc8941.html#parse-item
-        let bare_item = Parser::parse_bare_item(input_chars)?;
-        let params = Parser::parse_parameters(input_chars)?;
+    /// Checks whether input is a valid structured field value of Dictionary type without building it.
+    /// Accepts and rejects the same inputs as `Parser::parse_dictionary`.
+    /// Doesn't allocate, see `ParserConfig::validate_dictionary`.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert!(Parser::validate_dictionary("a=1, b=(2 3);c".as_bytes()).is_ok());
+    /// assert!(Parser::validate_dictionary("a=1,".as_bytes()).is_err());
+    /// ```
+    pub fn validate_dictionary(input_bytes: &[u8]) -> SFVResult<()> {
+        ParserConfig::default().validate_dictionary(input_bytes)
+    }
 
-        Ok(Item { bare_item, params })
+    /// Checks whether input is a valid structured field value of List type without building it.
+    /// Accepts and rejects the same inputs as `Parser::parse_list`.
+    /// Doesn't allocate.
+    pub fn validate_list(input_bytes: &[u8]) -> SFVResult<()> {
+        ParserConfig::default().validate_list(input_bytes)
     }
-}
 
-impl ParseValue for List {
-    fn parse(input_chars: &mut Peekable<Chars>) -> SFVResult<List> {
-        // https://httpwg.org/specs/rfc8941.html#parse-list
-        // List represents an array of (item_or_inner_list, parameters)
+    /// Checks whether input is a valid structured field value of Item type without building it.
+    /// Accepts and rejects the same inputs as `Parser::parse_item`.
+    /// Doesn't allocate.
+    pub fn validate_item(input_bytes: &[u8]) -> SFVResult<()> {
+        ParserConfig::default().validate_item(input_bytes)
+    }
+
+    /// Parses input into structured field value of List type,
+    /// pairing each member with its byte range in the input.
+    /// Ranges cover members with their parameters, but not the surrounding whitespace and commas.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// let input = "a;x, (1 2)";
+    /// let members = Parser::parse_list_spanned(input.as_bytes()).unwrap();
+    /// assert_eq!(&input[members[0].1.clone()], "a;x");
+    /// assert_eq!(&input[members[1].1.clone()], "(1 2)");
+    /// ```
+    pub fn parse_list_spanned(input_bytes: &[u8]) -> SFVResult<Vec<(ListEntry, Range<usize>)>> {
+        // Same as List parsing, but keeps track of the members' positions
+        let config = ParserConfig::default();
+        let mut input_chars = OffsetChars::new(Self::input_str(input_bytes)?);
+        utils::consume_sp_chars(&mut input_chars);
 
         let mut members = vec![];
+        Self::parse_list_members(&mut input_chars, &config, |input_chars| {
+            let start = input_chars.offset();
+            let member = Self::parse_list_entry(input_chars, &config)?;
+            members.push((member, start..input_chars.offset()));
+            Ok(())
+        })?;
+        Ok(members)
+    }
 
-        while input_chars.peek().is_some() {
-            members.push(Parser::parse_list_entry(input_chars)?);
+    /// Parses input into structured field value of List type, skipping members which fail to parse
+    /// instead of rejecting the whole field, e.g. for non-critical fields with non-conformant members.
+    /// Parsing of a failed member resumes after the next comma outside of strings and inner lists.
+    /// Returns successfully parsed members along with errors of the skipped ones.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let (list, errors) = Parser::parse_list_lossy("a, b c, \"d, e\"".as_bytes());
+    /// assert_eq!(list.serialize_value().unwrap(), "a, \"d, e\"");
+    /// assert_eq!(errors, vec!["parse_list: trailing characters after list member"]);
+    /// ```
+    pub fn parse_list_lossy(input_bytes: &[u8]) -> (List, Vec<&'static str>) {
+        let config = ParserConfig::default();
+        let mut input_chars = match Self::input_chars(input_bytes) {
+            Ok(input_chars) => input_chars,
+            Err(err) => return (vec![], vec![err]),
+        };
 
-            utils::consume_ows_chars(input_chars);
+        utils::consume_sp_chars(&mut input_chars);
 
-            if input_chars.peek().is_none() {
-                return Ok(members);
+        let mut members = vec![];
+        let mut errors = vec![];
+        while input_chars.peek().is_some() {
+            // Member is parsed on a copy of input, so that it can be skipped from its start on error
+            let mut member_chars = input_chars.clone();
+            let member = Self::parse_list_entry(&mut member_chars, &config).and_then(|member| {
+                utils::consume_ows_chars(&mut member_chars);
+                match member_chars.peek() {
+                    None | Some(',') => Ok(member),
+                    _ => Err("parse_list: trailing characters after list member"),
+                }
+            });
+
+            match member {
+                Ok(member) => {
+                    members.push(member);
+                    input_chars = member_chars;
+                    if input_chars.next().is_some() {
+                        utils::consume_ows_chars(&mut input_chars);
+                        if input_chars.peek().is_none() {
+                            errors.push("parse_list: trailing comma");
+                        }
+                    }
+                }
+                Err(err) => {
+                    errors.push(err);
+                    Self::skip_list_member(&mut input_chars);
+                    utils::consume_ows_chars(&mut input_chars);
+                }
             }
+        }
+        (members, errors)
+    }
 
-            if let Some(c) = input_chars.next() {
-                if c != ',' {
-                    return Err("parse_list: trailing characters after list member");
+    // Consumes input up to and including the next comma which is not a part of a string or an inner list
+    fn skip_list_member<I: Input>(input_chars: &mut I) {
+        let mut depth = 0_usize;
+        while let Some(curr_char) = input_chars.next() {
+            match curr_char {
+                '"' => {
+                    while let Some(c) = input_chars.next() {
+                        match c {
+                            '\\' => {
+                                input_chars.next();
+                            }
+                            '"' => break,
+                            _ => (),
+                        }
+                    }
                 }
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => return,
+                _ => (),
             }
+        }
+    }
 
-            utils::consume_ows_chars(input_chars);
+    /// Reads input from `reader` until EOF and parses it into structured field value of Dictionary type
+    #[cfg(feature = "std")]
+    pub fn parse_dictionary_reader<R: std::io::Read>(reader: R) -> SFVResult<Dictionary> {
+        Self::parse_dictionary(&Self::read_input(reader)?)
+    }
 
-            if input_chars.peek().is_none() {
-                return Err("parse_list: trailing comma");
-            }
+    /// Reads input from `reader` until EOF and parses it into structured field value of List type
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeValue};
+    /// let reader = std::io::Cursor::new("a, b;q=0.5");
+    /// let list = Parser::parse_list_reader(reader).unwrap();
+    /// assert_eq!(list.serialize_value().unwrap(), "a, b;q=0.5");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_list_reader<R: std::io::Read>(reader: R) -> SFVResult<List> {
+        Self::parse_list(&Self::read_input(reader)?)
+    }
+
+    /// Reads input from `reader` until EOF and parses it into structured field value of Item type
+    #[cfg(feature = "std")]
+    pub fn parse_item_reader<R: std::io::Read>(reader: R) -> SFVResult<Item> {
+        Self::parse_item(&Self::read_input(reader)?)
+    }
+
+    #[cfg(feature = "std")]
+    fn read_input<R: std::io::Read>(mut reader: R) -> SFVResult<Vec<u8>> {
+        let mut input_bytes = Vec::new();
+        reader
+            .read_to_end(&mut input_bytes)
+            .map_err(|_| "parse: failed to read input")?;
+        Ok(input_bytes)
+    }
+
+    // Generic parse method for checking input before parsing
+    // and handling trailing text error
+    fn parse<T: ParseValue>(input_bytes: &[u8], config: &ParserConfig) -> SFVResult<T> {
+        // https://httpwg.org/specs/rfc8941.html#text-parse
+        Self::parse_input(input_bytes, |input_chars| {
+            T::parse_with_config(input_chars, config)
+        })
+    }
+
+    // Same as parse, but for parsing functions which aren't ParseValue implementations
+    fn parse_input<T>(
+        input_bytes: &[u8],
+        parse_value: impl FnOnce(&mut Peekable<Chars>) -> SFVResult<T>,
+    ) -> SFVResult<T> {
+        let mut input_chars = Self::input_chars(input_bytes)?;
+        utils::consume_sp_chars(&mut input_chars);
+
+        let output = parse_value(&mut input_chars)?;
+
+        utils::consume_sp_chars(&mut input_chars);
+
+        if input_chars.next().is_some() {
+            return Err("parse: trailing characters after parsed value");
+        };
+        Ok(output)
+    }
+
+    fn input_chars(input_bytes: &[u8]) -> SFVResult<Peekable<Chars<'_>>> {
+        Ok(Self::input_str(input_bytes)?.chars().peekable())
+    }
+
+    fn input_str(input_bytes: &[u8]) -> SFVResult<&str> {
+        if !input_bytes.is_ascii() {
+            return Err("parse: non-ascii characters in input");
         }
 
+        from_utf8(input_bytes).map_err(|_| "parse: conversion from bytes to str failed")
+    }
+
+    fn parse_dictionary_with<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut Build,
+    ) -> SFVResult<Dictionary> {
+        let mut dict = Dictionary::default();
+        Self::parse_dictionary_members(input_chars, config, sink, |_, key, member| {
+            if config.reject_duplicate_keys && dict.contains_key(&key) {
+                return Err("parse_dict: duplicate key");
+            }
+            dict.insert(key, member);
+            Ok(())
+        })?;
+        Ok(dict)
+    }
+
+    fn parse_list_with<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut Build,
+    ) -> SFVResult<List> {
+        let mut members = vec![];
+        Self::parse_list_members(input_chars, config, |input_chars| {
+            members.push(Self::list_entry(input_chars, config, sink)?);
+            Ok(())
+        })?;
         Ok(members)
     }
-}
 
-impl ParseValue for Dictionary {
-    fn parse(input_chars: &mut Peekable<Chars>) -> SFVResult<Dictionary> {
-        let mut dict = Dictionary::new();
+    // Parses Dictionary members, passing each of them to on_member, including the ones with duplicate keys
+    fn parse_dictionary_members<I: Input, S: Sink>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut S,
+        mut on_member: impl FnMut(&mut S, S::Key, S::Entry) -> SFVResult<()>,
+    ) -> SFVResult<()> {
+        // https://httpwg.org/specs/rfc8941.html#parse-dictionary
 
         while input_chars.peek().is_some() {
-            let this_key = Parser::parse_key(input_chars)?;
+            let this_key = sink.key(input_chars)?;
+            sink.on_dictionary_key(&this_key, config)?;
+            Self::consume_lenient_ows_before(input_chars, '=', config);
 
-            if let Some('=') = input_chars.peek() {
+            let member = if let Some('=') = input_chars.peek() {
                 input_chars.next();
-                let member = Parser::parse_list_entry(input_chars)?;
-                dict.insert(this_key, member);
+                Self::consume_lenient_ows_after(input_chars, config);
+                Self::list_entry(input_chars, config, sink)?
             } else {
-                let value = true;
-                let params = Parser::parse_parameters(input_chars)?;
-                let member = Item {
-                    bare_item: BareItem::Boolean(value),
-                    params,
-                };
-                dict.insert(this_key, member.into());
-            }
+                let bare_item = sink.true_value();
+                sink.on_item(&bare_item)?;
+                let params = Self::parameters(input_chars, config, sink)?;
+                sink.item(bare_item, params).into()
+            };
+            on_member(sink, this_key, member)?;
 
             utils::consume_ows_chars(input_chars);
 
             if input_chars.peek().is_none() {
-                return Ok(dict);
+                return Ok(());
             }
 
             if let Some(c) = input_chars.next() {
@@ -112,94 +1403,91 @@ impl ParseValue for Dictionary {
             utils::consume_ows_chars(input_chars);
 
             if input_chars.peek().is_none() {
+                if config.allow_trailing_comma {
+                    break;
+                }
                 return Err("parse_dict: trailing comma");
             }
         }
-        Ok(dict)
-    }
-}
-
-impl ParseMore for List {
-    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()> {
-        let parsed_list = Parser::parse_list(input_bytes)?;
-        self.extend(parsed_list);
-        Ok(())
-    }
-}
-
-impl ParseMore for Dictionary {
-    fn parse_more(&mut self, input_bytes: &[u8]) -> SFVResult<()> {
-        let parsed_dict = Parser::parse_dictionary(input_bytes)?;
-        self.extend(parsed_dict);
         Ok(())
     }
-}
-
-/// Exposes methods for parsing input into structured field value.
-pub struct Parser;
 
-impl Parser {
-    /// Parses input into structured field value of Dictionary type
-    pub fn parse_dictionary(input_bytes: &[u8]) -> SFVResult<Dictionary> {
-        Self::parse::<Dictionary>(input_bytes)
-    }
+    // Parses List members separated by commas, calling parse_member at the start of each of them
+    fn parse_list_members<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        mut parse_member: impl FnMut(&mut I) -> SFVResult<()>,
+    ) -> SFVResult<()> {
+        // https://httpwg.org/specs/rfc8941.html#parse-list
+        // List represents an array of (item_or_inner_list, parameters)
 
-    /// Parses input into structured field value of List type
-    pub fn parse_list(input_bytes: &[u8]) -> SFVResult<List> {
-        Self::parse::<List>(input_bytes)
-    }
+        while input_chars.peek().is_some() {
+            parse_member(input_chars)?;
 
-    /// Parses input into structured field value of Item type
-    pub fn parse_item(input_bytes: &[u8]) -> SFVResult<Item> {
-        Self::parse::<Item>(input_bytes)
-    }
+            utils::consume_ows_chars(input_chars);
 
-    // Generic parse method for checking input before parsing
-    // and handling trailing text error
-    fn parse<T: ParseValue>(input_bytes: &[u8]) -> SFVResult<T> {
-        // https://httpwg.org/specs/rfc8941.html#text-parse
-        if !input_bytes.is_ascii() {
-            return Err("parse: non-ascii characters in input");
-        }
+            if input_chars.peek().is_none() {
+                return Ok(());
+            }
 
-        let mut input_chars = from_utf8(input_bytes)
-            .map_err(|_| "parse: conversion from bytes to str failed")?
-            .chars()
-            .peekable();
-        utils::consume_sp_chars(&mut input_chars);
+            if let Some(c) = input_chars.next() {
+                if c != ',' {
+                    return Err("parse_list: trailing characters after list member");
+                }
+            }
 
-        let output = T::parse(&mut input_chars)?;
+            utils::consume_ows_chars(input_chars);
 
-        utils::consume_sp_chars(&mut input_chars);
+            if input_chars.peek().is_none() {
+                if config.allow_trailing_comma {
+                    break;
+                }
+                return Err("parse_list: trailing comma");
+            }
+        }
+        Ok(())
+    }
 
-        if input_chars.next().is_some() {
-            return Err("parse: trailing characters after parsed value");
-        };
-        Ok(output)
+    fn parse_list_entry<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<ListEntry> {
+        Self::list_entry(input_chars, config, &mut Build::default())
     }
 
-    fn parse_list_entry(input_chars: &mut Peekable<Chars>) -> SFVResult<ListEntry> {
+    fn list_entry<I: Input, S: Sink>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut S,
+    ) -> SFVResult<S::Entry> {
         // https://httpwg.org/specs/rfc8941.html#parse-item-or-list
         // ListEntry represents a tuple (item_or_inner_list, parameters)
 
         match input_chars.peek() {
-            Some('(') => {
-                let parsed = Self::parse_inner_list(input_chars)?;
-                Ok(ListEntry::InnerList(parsed))
-            }
-            _ => {
-                let parsed = Item::parse(input_chars)?;
-                Ok(ListEntry::Item(parsed))
-            }
+            Some('(') => Ok(Self::inner_list(input_chars, config, sink)?.into()),
+            _ => Ok(Self::item(input_chars, config, sink)?.into()),
         }
     }
 
-    pub(crate) fn parse_inner_list(input_chars: &mut Peekable<Chars>) -> SFVResult<InnerList> {
+    #[cfg(test)]
+    pub(crate) fn parse_inner_list<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<InnerList> {
+        Self::inner_list(input_chars, config, &mut Build::default())
+    }
+
+    fn inner_list<I: Input, S: Sink>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut S,
+    ) -> SFVResult<S::InnerList> {
         // https://httpwg.org/specs/rfc8941.html#parse-innerlist
 
         if Some('(') != input_chars.next() {
             return Err("parse_inner_list: input does not start with '('");
         }
+        sink.on_inner_list_start()?;
 
         let mut inner_list = Vec::new();
         while input_chars.peek().is_some() {
@@ -207,14 +1495,17 @@ impl Parser {
 
             if Some(&')') == input_chars.peek() {
                 input_chars.next();
-                let params = Self::parse_parameters(input_chars)?;
-                return Ok(InnerList {
-                    items: inner_list,
-                    params,
-                });
+                sink.on_inner_list_end()?;
+                let params = Self::parameters(input_chars, config, sink)?;
+                return Ok(sink.inner_list(inner_list, params));
+            }
+
+            // Inner list members are items only, inner lists can't be nested
+            if Some(&'(') == input_chars.peek() {
+                return Err("parse_inner_list: nested inner list");
             }
 
-            let parsed_item = Item::parse(input_chars)?;
+            let parsed_item = Self::item(input_chars, config, sink)?;
             inner_list.push(parsed_item);
 
             if let Some(c) = input_chars.peek() {
@@ -227,28 +1518,59 @@ impl Parser {
         Err("parse_inner_list: the end of the inner list was not found")
     }
 
-    pub(crate) fn parse_bare_item(input_chars: &mut Peekable<Chars>) -> SFVResult<BareItem> {
-        // https://httpwg.org/specs/rfc8941.html#parse-bare-item
+    fn parse_item_with<I: Input>(input_chars: &mut I, config: &ParserConfig) -> SFVResult<Item> {
+        Self::item(input_chars, config, &mut Build::default())
+    }
+
+    fn item<I: Input, S: Sink>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut S,
+    ) -> SFVResult<S::Item> {
+        // https://httpwg.org/specs/rfc8941.html#parse-item
+        let bare_item = sink.bare_item(input_chars, config)?;
+        sink.on_item(&bare_item)?;
+        let params = Self::parameters(input_chars, config, sink)?;
+
+        Ok(sink.item(bare_item, params))
+    }
+
+    fn parse_bare_item_with<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<BareItem> {
+        Build::default().bare_item(input_chars, config)
+    }
+
+    // Same as parse_bare_item_with, but only checks the bare item without building it
+    fn scan_bare_item<I: Input>(input_chars: &mut I, config: &ParserConfig) -> SFVResult<()> {
         if input_chars.peek().is_none() {
             return Err("parse_bare_item: empty item");
         }
 
         match input_chars.peek() {
-            Some(&'?') => Ok(BareItem::Boolean(Self::parse_bool(input_chars)?)),
-            Some(&'"') => Ok(BareItem::String(Self::parse_string(input_chars)?)),
-            Some(&':') => Ok(BareItem::ByteSeq(Self::parse_byte_sequence(input_chars)?)),
+            Some(&'?') => Self::parse_bool(input_chars).map(|_| ()),
+            Some(&'"') => Self::scan_string(input_chars, &mut Discard),
+            Some(&':') => {
+                let unpadded_len = Self::scan_byte_sequence(input_chars, config, &mut Discard)?;
+                // Content is valid base64 at this point, except for a single character
+                // in the last group, which is too short to encode a byte
+                if unpadded_len % 4 == 1 {
+                    return Err("parse_byte_seq: decoding error");
+                }
+                Ok(())
+            }
             Some(&c) if c == '*' || c.is_ascii_alphabetic() => {
-                Ok(BareItem::Token(Self::parse_token(input_chars)?))
+                Self::scan_token(input_chars, &mut Discard)
+            }
+            Some(&c) if c == '-' || c.is_ascii_digit() => {
+                Self::parse_number(input_chars, config).map(|_| ())
             }
-            Some(&c) if c == '-' || c.is_ascii_digit() => match Self::parse_number(input_chars)? {
-                Num::Decimal(val) => Ok(BareItem::Decimal(val)),
-                Num::Integer(val) => Ok(BareItem::Integer(val)),
-            },
             _ => Err("parse_bare_item: item type can't be identified"),
         }
     }
 
-    pub(crate) fn parse_bool(input_chars: &mut Peekable<Chars>) -> SFVResult<bool> {
+    pub(crate) fn parse_bool<I: Input>(input_chars: &mut I) -> SFVResult<bool> {
         // https://httpwg.org/specs/rfc8941.html#parse-boolean
 
         if input_chars.next() != Some('?') {
@@ -262,18 +1584,30 @@ impl Parser {
         }
     }
 
-    pub(crate) fn parse_string(input_chars: &mut Peekable<Chars>) -> SFVResult<String> {
+    pub(crate) fn parse_string<I: Input>(input_chars: &mut I) -> SFVResult<String> {
+        Build::default().text(input_chars, Self::scan_string)
+    }
+
+    fn scan_string<I: Input>(
+        input_chars: &mut I,
+        output_string: &mut impl CharOutput,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#parse-string
 
         if input_chars.next() != Some('\"') {
             return Err("parse_string: first character is not '\"'");
         }
 
-        let mut output_string = String::from("");
-        while let Some(curr_char) = input_chars.next() {
+        while let Some(&curr_char) = input_chars.peek() {
+            // Disallowed characters are not consumed, so that input is positioned at the offending character
             match curr_char {
-                '\"' => return Ok(output_string),
                 '\x7f' | '\x00'..='\x1f' => return Err("parse_string: not a visible character"),
+                c if !c.is_ascii() => return Err("parse_string: non-ascii character"),
+                _ => input_chars.next(),
+            };
+
+            match curr_char {
+                '\"' => return Ok(()),
                 '\\' => match input_chars.next() {
                     Some(c) if c == '\\' || c == '\"' => {
                         output_string.push(c);
@@ -287,7 +1621,15 @@ impl Parser {
         Err("parse_string: no closing '\"'")
     }
 
-    pub(crate) fn parse_token(input_chars: &mut Peekable<Chars>) -> SFVResult<String> {
+    #[cfg(test)]
+    pub(crate) fn parse_token<I: Input>(input_chars: &mut I) -> SFVResult<String> {
+        Build::default().text(input_chars, Self::scan_token)
+    }
+
+    fn scan_token<I: Input>(
+        input_chars: &mut I,
+        output_string: &mut impl CharOutput,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#parse-token
 
         if let Some(first_char) = input_chars.peek() {
@@ -298,10 +1640,9 @@ impl Parser {
             return Err("parse_token: empty input string");
         }
 
-        let mut output_string = String::from("");
         while let Some(curr_char) = input_chars.peek() {
             if !utils::is_tchar(*curr_char) && curr_char != &':' && curr_char != &'/' {
-                return Ok(output_string);
+                return Ok(());
             }
 
             match input_chars.next() {
@@ -309,10 +1650,56 @@ impl Parser {
                 None => return Err("parse_token: end of the string"),
             }
         }
-        Ok(output_string)
+        Ok(())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn parse_byte_sequence<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<Vec<u8>> {
+        Build::default().byte_sequence(input_chars, config)
+    }
+
+    fn parse_byte_sequence_into<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        output: &mut Vec<u8>,
+    ) -> SFVResult<()> {
+        let mut b64_content = String::new();
+        Self::scan_byte_sequence(input_chars, config, &mut b64_content)?;
+        Self::decode_byte_sequence(&b64_content, output)
+    }
+
+    // Decodes base64 content checked by scan_byte_sequence into output, replacing its previous content
+    fn decode_byte_sequence(b64_content: &str, output: &mut Vec<u8>) -> SFVResult<()> {
+        // Padding is valid at this point, so the buffer can be sized from the unpadded content
+        let b64_content = b64_content.trim_end_matches('=').as_bytes();
+        let base64 = utils::base64()?;
+        let decoded_len = base64
+            .decode_len(b64_content.len())
+            .map_err(|_| "parse_byte_seq: decoding error")?;
+        output.clear();
+        output.resize(decoded_len, 0);
+        match base64.decode_mut(b64_content, output) {
+            Ok(len) => {
+                output.truncate(len);
+                Ok(())
+            }
+            Err(_) => {
+                output.clear();
+                Err("parse_byte_seq: decoding error")
+            }
+        }
     }
 
-    pub(crate) fn parse_byte_sequence(input_chars: &mut Peekable<Chars>) -> SFVResult<Vec<u8>> {
+    // Checks byte sequence delimiters and base64 content, passing the content to b64_content.
+    // Returns the content length without padding.
+    fn scan_byte_sequence<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        b64_content: &mut impl CharOutput,
+    ) -> SFVResult<usize> {
         // https://httpwg.org/specs/rfc8941.html#parse-binary
 
         if input_chars.next() != Some(':') {
@@ -323,17 +1710,93 @@ impl Parser {
             return Err("parse_byte_seq: no closing ':'");
         }
 
-        let b64_content = input_chars.take_while(|c| c != &':').collect::<String>();
-        if !b64_content.chars().all(utils::is_allowed_b64_content) {
+        let mut content_len = 0;
+        let mut padding_len = 0;
+        let (mut url_safe, mut invalid_char, mut misplaced_padding) = (false, false, false);
+        for c in input_chars.by_ref().take_while(|c| c != &':') {
+            url_safe |= c == '-' || c == '_';
+            invalid_char |= !utils::is_allowed_b64_content(c);
+            if c == '=' {
+                padding_len += 1;
+            } else if padding_len > 0 {
+                misplaced_padding = true;
+            }
+            content_len += 1;
+            b64_content.push(c);
+        }
+
+        if url_safe {
+            return Err("parse_byte_seq: url-safe base64 alphabet is not allowed");
+        }
+        if invalid_char {
             return Err("parse_byte_seq: invalid char in byte sequence");
         }
-        match utils::base64()?.decode(b64_content.as_bytes()) {
-            Ok(content) => Ok(content),
-            Err(_) => Err("parse_byte_seq: decoding error"),
+        // Parsers SHOULD NOT fail when "=" padding is not present,
+        // but padding that is present can only appear at the end, and it must complete the last 4-char group
+        if padding_len > 0 && (misplaced_padding || padding_len > 2 || content_len % 4 != 0) {
+            return Err("parse_byte_seq: invalid padding");
+        }
+        let unpadded_len = content_len - padding_len;
+        if let Some(max_len) = config.max_byte_seq_len {
+            // Every 4 base64 characters encode 3 bytes, incomplete groups encode 1 or 2 bytes
+            let decoded_len = unpadded_len * 3 / 4;
+            if decoded_len > max_len {
+                return Err("parse_byte_seq: byte sequence is too long");
+            }
+        }
+        Ok(unpadded_len)
+    }
+
+    /// Parses input into a single byte sequence, i.e. base64 content between `:` delimiters,
+    /// and decodes it into `buffer`, replacing its previous content.
+    /// Unlike `parse_bare_item`, doesn't allocate when `buffer` has enough capacity,
+    /// so the same buffer can be reused for parsing many byte sequences.
+    /// Input must not contain anything but the byte sequence and surrounding spaces.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// let mut buffer = Vec::with_capacity(16);
+    /// Parser::parse_byte_seq_into(":aGVsbG8=:".as_bytes(), &mut buffer).unwrap();
+    /// assert_eq!(buffer, b"hello");
+    /// Parser::parse_byte_seq_into(":aGk=:".as_bytes(), &mut buffer).unwrap();
+    /// assert_eq!(buffer, b"hi");
+    /// ```
+    pub fn parse_byte_seq_into(input_bytes: &[u8], buffer: &mut Vec<u8>) -> SFVResult<()> {
+        let mut input_chars = Self::input_chars(input_bytes)?;
+        utils::consume_sp_chars(&mut input_chars);
+
+        Self::parse_byte_sequence_into(&mut input_chars, &ParserConfig::default(), buffer)?;
+
+        utils::consume_sp_chars(&mut input_chars);
+
+        if input_chars.next().is_some() {
+            return Err("parse: trailing characters after parsed value");
+        };
+        Ok(())
+    }
+
+    /// Decodes base64 content of a byte sequence, i.e. characters between `:` delimiters.
+    /// Unlike byte sequence parsing, which tolerates missing `=` padding as recommended by RFC 8941,
+    /// requires padding to be present and the unused trailing bits to be zero.
+    /// # Examples
+    /// ```
+    /// # use sfv::Parser;
+    /// assert_eq!(Parser::decode_byte_seq_strict("aGVsbG8=".as_bytes()).unwrap(), b"hello");
+    /// assert!(Parser::decode_byte_seq_strict("aGVsbG8".as_bytes()).is_err());
+    /// ```
+    pub fn decode_byte_seq_strict(b64_content: &[u8]) -> SFVResult<Vec<u8>> {
+        if b64_content.iter().any(|c| c == &b'-' || c == &b'_') {
+            return Err("decode_byte_seq_strict: url-safe base64 alphabet is not allowed");
         }
+        BASE64
+            .decode(b64_content)
+            .map_err(|_| "decode_byte_seq_strict: decoding error")
     }
 
-    pub(crate) fn parse_number(input_chars: &mut Peekable<Chars>) -> SFVResult<Num> {
+    pub(crate) fn parse_number<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<Num> {
         // https://httpwg.org/specs/rfc8941.html#parse-number
 
         let mut sign = 1;
@@ -351,7 +1814,8 @@ impl Parser {
         }
 
         // Get number from input as a string and identify whether it's a decimal or integer
-        let (is_integer, input_number) = Self::extract_digits(input_chars)?;
+        let (is_integer, input_number) = Self::extract_digits(input_chars, config)?;
+        let input_number = input_number.as_str();
 
         // Parse input_number from string into integer
         if is_integer {
@@ -360,8 +1824,7 @@ impl Parser {
                 .map_err(|_err| "parse_number: parsing i64 failed")?
                 * sign;
 
-            let (min_int, max_int) = (-999_999_999_999_999_i64, 999_999_999_999_999_i64);
-            if !(min_int <= output_number && output_number <= max_int) {
+            if !(MIN_INTEGER..=MAX_INTEGER).contains(&output_number) {
                 return Err("parse_number: integer number is out of range");
             }
 
@@ -375,25 +1838,38 @@ impl Parser {
 
         match chars_after_dot {
             Some(0) => Err("parse_number: decimal ends with '.'"),
+            Some(2..=3) if config.strict_numbers && input_number.ends_with('0') => {
+                Err("parse_number: trailing zeros in fractional component are not allowed")
+            }
             Some(1..=3) => {
-                let mut output_number = Decimal::from_str(&input_number)
+                let mut output_number = Decimal::from_str(input_number)
                     .map_err(|_err| "parse_number: parsing f64 failed")?;
 
                 if sign == -1 {
                     output_number.set_sign_negative(true)
                 }
 
+                if !config.preserve_decimal_scale {
+                    output_number = output_number.normalize();
+                }
+
                 Ok(Num::Decimal(output_number))
             }
             _ => Err("parse_number: invalid decimal fraction length"),
         }
     }
 
-    fn extract_digits(input_chars: &mut Peekable<Chars>) -> SFVResult<(bool, String)> {
+    fn extract_digits<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<(bool, utils::NumberBuffer)> {
         let mut is_integer = true;
-        let mut input_number = String::from("");
+        let mut input_number = utils::NumberBuffer::new();
         while let Some(curr_char) = input_chars.peek() {
             if curr_char.is_ascii_digit() {
+                if config.strict_numbers && input_number.as_str() == "0" {
+                    return Err("parse_number: leading zeros are not allowed");
+                }
                 input_number.push(*curr_char);
                 input_chars.next();
             } else if curr_char == &'.' && is_integer {
@@ -409,6 +1885,8 @@ impl Parser {
                 break;
             }
 
+            // sf-integer range is limited to 15 digits, reject longer input
+            // before it has a chance to overflow
             if is_integer && input_number.len() > 15 {
                 return Err("parse_number: integer too long, length > 15");
             }
@@ -420,54 +1898,95 @@ impl Parser {
         Ok((is_integer, input_number))
     }
 
-    pub(crate) fn parse_parameters(input_chars: &mut Peekable<Chars>) -> SFVResult<Parameters> {
+    #[cfg(test)]
+    pub(crate) fn parse_parameters<I: Input>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+    ) -> SFVResult<Parameters> {
+        Self::parameters(input_chars, config, &mut Build::default())
+    }
+
+    fn parameters<I: Input, S: Sink>(
+        input_chars: &mut I,
+        config: &ParserConfig,
+        sink: &mut S,
+    ) -> SFVResult<S::Params> {
         // https://httpwg.org/specs/rfc8941.html#parse-param
 
-        let mut params = Parameters::new();
+        let mut params = S::Params::default();
 
-        while let Some(curr_char) = input_chars.peek() {
-            if curr_char == &';' {
-                input_chars.next();
-            } else {
+        loop {
+            Self::consume_lenient_ows_before(input_chars, ';', config);
+            if input_chars.peek() != Some(&';') {
                 break;
             }
+            input_chars.next();
 
             utils::consume_sp_chars(input_chars);
+            Self::consume_lenient_ows_after(input_chars, config);
 
-            let param_name = Self::parse_key(input_chars)?;
+            let param_name = sink.key(input_chars)?;
+            Self::consume_lenient_ows_before(input_chars, '=', config);
             let param_value = match input_chars.peek() {
                 Some('=') => {
                     input_chars.next();
-                    Self::parse_bare_item(input_chars)?
+                    Self::consume_lenient_ows_after(input_chars, config);
+                    sink.bare_item(input_chars, config)?
                 }
-                _ => BareItem::Boolean(true),
+                _ => sink.true_value(),
             };
-            params.insert(param_name, param_value);
+            sink.parameter(&mut params, param_name, param_value)?;
         }
-
-        // If parameters already contains a name param_name (comparing character-for-character), overwrite its value.
-        // Note that when duplicate Parameter keys are encountered, this has the effect of ignoring all but the last instance.
         Ok(params)
     }
 
-    pub(crate) fn parse_key(input_chars: &mut Peekable<Chars>) -> SFVResult<String> {
+    // In lenient whitespace mode skips whitespace preceding `separator`.
+    // Whitespace which is not followed by `separator` is left in place.
+    fn consume_lenient_ows_before<I: Input>(
+        input_chars: &mut I,
+        separator: char,
+        config: &ParserConfig,
+    ) {
+        if !config.lenient_whitespace {
+            return;
+        }
+
+        let mut lookahead = input_chars.clone();
+        utils::consume_ows_chars(&mut lookahead);
+        if lookahead.peek() == Some(&separator) {
+            *input_chars = lookahead;
+        }
+    }
+
+    // In lenient whitespace mode skips whitespace following a separator
+    fn consume_lenient_ows_after<I: Input>(input_chars: &mut I, config: &ParserConfig) {
+        if config.lenient_whitespace {
+            utils::consume_ows_chars(input_chars);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn parse_key<I: Input>(input_chars: &mut I) -> SFVResult<String> {
+        Build::default().text(input_chars, Self::scan_key)
+    }
+
+    fn scan_key<I: Input>(input_chars: &mut I, output: &mut impl CharOutput) -> SFVResult<()> {
         match input_chars.peek() {
             Some(c) if c == &'*' || c.is_ascii_lowercase() => (),
             _ => return Err("parse_key: first character is not lcalpha or '*'"),
         }
 
-        let mut output = String::new();
         while let Some(curr_char) = input_chars.peek() {
             if !curr_char.is_ascii_lowercase()
                 && !curr_char.is_ascii_digit()
                 && !"_-*.".contains(*curr_char)
             {
-                return Ok(output);
+                return Ok(());
             }
 
             output.push(*curr_char);
             input_chars.next();
         }
-        Ok(output)
+        Ok(())
     }
 }
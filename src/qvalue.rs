@@ -0,0 +1,333 @@
+/*!
+Helpers for lists which members are weighted with `q` parameter, e.g. `gzip;q=0.8, br`.
+
+Quality value is either a `Decimal` or an `Integer` in `[0, 1]` range.
+Members without `q` parameter have quality value of `1`.
+`QValue` wraps it, optionally clamping out of range values instead of rejecting them.
+
+# Examples
+```
+use sfv::{qvalue, Parser, SerializeValue};
+
+let mut list = Parser::parse_list("a;q=0.3, b, c;q=0.7".as_bytes()).unwrap();
+qvalue::sort_by_q(&mut list).unwrap();
+assert_eq!(list.serialize_value().unwrap(), "b, c;q=0.7, a;q=0.3");
+```
+*/
+
+use crate::{BareItem, Decimal, List, ListEntry, Parameters, Parser, SFVResult};
+use alloc::vec::Vec;
+
+/// Returns quality value of `q` parameter, or `1` if there's no such parameter.
+/// Fails if `q` is not a number or is out of `[0, 1]` range.
+pub fn q_value(params: &Parameters) -> SFVResult<Decimal> {
+    QValue::from_params(params, false).map(QValue::value)
+}
+
+/// Quality value, i.e. a `Decimal` in `[0, 1]` range. Defaults to `1`, the quality value of members without `q` parameter.
+/// # Examples
+/// ```
+/// use sfv::qvalue::QValue;
+/// use sfv::{Decimal, FromStr, Parser};
+///
+/// let item = Parser::parse_item("gzip;q=0.5".as_bytes()).unwrap();
+/// let q = QValue::from_params(&item.params, false).unwrap();
+/// assert_eq!(q.value(), Decimal::from_str("0.5").unwrap());
+///
+/// let item = Parser::parse_item("br;q=2".as_bytes()).unwrap();
+/// assert!(QValue::from_params(&item.params, false).is_err());
+/// assert_eq!(QValue::from_params(&item.params, true), Ok(QValue::ONE));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QValue(Decimal);
+
+impl QValue {
+    /// Quality value of `0`, i.e. "not acceptable".
+    pub const ZERO: QValue = QValue(Decimal::ZERO);
+    /// Quality value of `1`, the default one.
+    pub const ONE: QValue = QValue(Decimal::ONE);
+
+    /// Returns new `QValue`. Fails if `value` is out of `[0, 1]` range.
+    pub fn new(value: Decimal) -> SFVResult<QValue> {
+        if value < Decimal::ZERO || value > Decimal::ONE {
+            return Err("q_value: q parameter is out of range");
+        }
+        Ok(QValue(value))
+    }
+
+    /// Returns new `QValue`, clamping `value` into `[0, 1]` range.
+    pub fn new_clamped(value: Decimal) -> QValue {
+        QValue(value.max(Decimal::ZERO).min(Decimal::ONE))
+    }
+
+    /// Returns `QValue` of `Decimal` or `Integer` bare item.
+    /// Out of range value is clamped if `clamp` is `true`, otherwise it's an error.
+    /// Fails if bare item is not a number.
+    pub fn from_bare_item(bare_item: &BareItem, clamp: bool) -> SFVResult<QValue> {
+        let value = match bare_item {
+            BareItem::Decimal(value) => *value,
+            BareItem::Integer(value) => Decimal::from(*value),
+            _ => return Err("q_value: q parameter is not a number"),
+        };
+
+        if clamp {
+            Ok(QValue::new_clamped(value))
+        } else {
+            QValue::new(value)
+        }
+    }
+
+    /// Returns `QValue` of `q` parameter, or `QValue::ONE` if there's no such parameter.
+    /// Out of range value is clamped if `clamp` is `true`, otherwise it's an error.
+    /// Fails if `q` is not a number.
+    pub fn from_params(params: &Parameters, clamp: bool) -> SFVResult<QValue> {
+        match params.get("q") {
+            Some(q) => QValue::from_bare_item(q, clamp),
+            None => Ok(QValue::ONE),
+        }
+    }
+
+    /// Returns quality value as `Decimal`.
+    pub fn value(self) -> Decimal {
+        self.0
+    }
+}
+
+impl Default for QValue {
+    fn default() -> Self {
+        QValue::ONE
+    }
+}
+
+impl From<QValue> for Decimal {
+    fn from(q: QValue) -> Self {
+        q.value()
+    }
+}
+
+impl From<QValue> for BareItem {
+    fn from(q: QValue) -> Self {
+        BareItem::Decimal(q.value())
+    }
+}
+
+/// Sorts `List` members by their quality value in descending order.
+/// Sort is stable, so members with equal quality values keep their relative order.
+/// If any of the members has invalid quality value, the list is left unchanged.
+pub fn sort_by_q(list: &mut List) -> SFVResult<()> {
+    let q_values = list
+        .iter()
+        .map(|member| q_value(member_params(member)))
+        .collect::<SFVResult<Vec<Decimal>>>()?;
+
+    let mut weighted: Vec<(Decimal, ListEntry)> =
+        q_values.into_iter().zip(list.drain(..)).collect();
+    weighted.sort_by(|(q_a, _), (q_b, _)| q_b.cmp(q_a));
+    list.extend(weighted.into_iter().map(|(_, member)| member));
+    Ok(())
+}
+
+/// Parses input into `List` of `Items` and returns their bare items paired with quality values,
+/// sorted by quality value in descending order, same as `sort_by_q`.
+/// Fails if input is not a valid `List`, if it contains an `InnerList`,
+/// or if any of the members has invalid quality value.
+/// # Examples
+/// ```
+/// use sfv::{qvalue, BareItem, Decimal, FromStr};
+///
+/// let weighted = qvalue::parse_weighted("gzip;q=0.5, br, identity;q=0".as_bytes()).unwrap();
+/// assert_eq!(weighted[0], (BareItem::Token("br".into()), Decimal::ONE));
+/// assert_eq!(weighted[1], (BareItem::Token("gzip".into()), Decimal::from_str("0.5").unwrap()));
+/// assert_eq!(weighted[2], (BareItem::Token("identity".into()), Decimal::ZERO));
+/// ```
+pub fn parse_weighted(input_bytes: &[u8]) -> SFVResult<Vec<(BareItem, Decimal)>> {
+    let mut weighted = Parser::parse_list(input_bytes)?
+        .into_iter()
+        .map(|member| match member {
+            ListEntry::Item(item) => Ok((q_value(&item.params)?, item.bare_item)),
+            ListEntry::InnerList(_) => Err("parse_weighted: list member is not an item"),
+        })
+        .collect::<SFVResult<Vec<(Decimal, BareItem)>>>()?;
+
+    weighted.sort_by(|(q_a, _), (q_b, _)| q_b.cmp(q_a));
+    Ok(weighted
+        .into_iter()
+        .map(|(q, bare_item)| (bare_item, q))
+        .collect())
+}
+
+fn member_params(member: &ListEntry) -> &Parameters {
+    match member {
+        ListEntry::Item(item) => &item.params,
+        ListEntry::InnerList(inner_list) => &inner_list.params,
+    }
+}
+
+#[cfg(test)]
+mod qvalue_tests {
+    use super::*;
+    use crate::{FromStr, SerializeValue};
+    use std::error::Error;
+
+    #[test]
+    fn get_q_value() -> Result<(), Box<dyn Error>> {
+        let item = Parser::parse_item("a;q=0.5".as_bytes())?;
+        assert_eq!(Decimal::from_str("0.5")?, q_value(&item.params)?);
+
+        let item = Parser::parse_item("a;q=0".as_bytes())?;
+        assert_eq!(Decimal::ZERO, q_value(&item.params)?);
+
+        let item = Parser::parse_item("a;x=2".as_bytes())?;
+        assert_eq!(Decimal::ONE, q_value(&item.params)?);
+        Ok(())
+    }
+
+    #[test]
+    fn get_q_value_errors() -> Result<(), Box<dyn Error>> {
+        let item = Parser::parse_item("a;q=1.5".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is out of range"),
+            q_value(&item.params)
+        );
+
+        let item = Parser::parse_item("a;q=-0.1".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is out of range"),
+            q_value(&item.params)
+        );
+
+        let item = Parser::parse_item("a;q".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is not a number"),
+            q_value(&item.params)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn q_value_new() -> Result<(), Box<dyn Error>> {
+        let half = Decimal::from_str("0.5")?;
+        assert_eq!(half, QValue::new(half)?.value());
+        assert_eq!(QValue::ZERO, QValue::new(Decimal::ZERO)?);
+        assert_eq!(QValue::ONE, QValue::new(Decimal::ONE)?);
+        assert_eq!(QValue::ONE, QValue::default());
+        assert_eq!(
+            Err("q_value: q parameter is out of range"),
+            QValue::new(Decimal::from_str("1.001")?)
+        );
+
+        assert_eq!(QValue::ONE, QValue::new_clamped(Decimal::from(2)));
+        assert_eq!(
+            QValue::ZERO,
+            QValue::new_clamped(Decimal::from_str("-0.1")?)
+        );
+        assert_eq!(half, QValue::new_clamped(half).value());
+        assert!(QValue::ZERO < QValue::new(half)?);
+        Ok(())
+    }
+
+    #[test]
+    fn q_value_from_params() -> Result<(), Box<dyn Error>> {
+        let item = Parser::parse_item("a;q=0.25".as_bytes())?;
+        let q = QValue::from_params(&item.params, false)?;
+        assert_eq!(Decimal::from_str("0.25")?, q.value());
+        assert_eq!(q, QValue::from_params(&item.params, true)?);
+
+        let item = Parser::parse_item("a;x".as_bytes())?;
+        assert_eq!(QValue::ONE, QValue::from_params(&item.params, false)?);
+
+        let item = Parser::parse_item("a;q=2".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is out of range"),
+            QValue::from_params(&item.params, false)
+        );
+        assert_eq!(QValue::ONE, QValue::from_params(&item.params, true)?);
+
+        let item = Parser::parse_item("a;q=-1.5".as_bytes())?;
+        assert_eq!(QValue::ZERO, QValue::from_params(&item.params, true)?);
+
+        // Clamping doesn't make non-numbers valid
+        let item = Parser::parse_item("a;q=?1".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is not a number"),
+            QValue::from_params(&item.params, true)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn q_value_into_bare_item() -> Result<(), Box<dyn Error>> {
+        let q = QValue::new(Decimal::from_str("0.8")?)?;
+        let item = crate::Item::new(q.into());
+        assert_eq!("0.8", item.serialize_value()?);
+        assert_eq!(Decimal::ONE, Decimal::from(QValue::ONE));
+        assert_eq!(
+            QValue::ZERO,
+            QValue::from_bare_item(&BareItem::Integer(0), false)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sort_list_by_q() -> Result<(), Box<dyn Error>> {
+        let mut list = Parser::parse_list("a;q=0.3, b, c;q=0.7".as_bytes())?;
+        sort_by_q(&mut list)?;
+        assert_eq!("b, c;q=0.7, a;q=0.3", list.serialize_value()?);
+
+        // Equal quality values keep their order, inner lists are weighted by their own parameters
+        let mut list = Parser::parse_list("a;q=0.5, (b c);q=1, d;q=0.5, e;q=1".as_bytes())?;
+        sort_by_q(&mut list)?;
+        assert_eq!(
+            "(b c);q=1, e;q=1, a;q=0.5, d;q=0.5",
+            list.serialize_value()?
+        );
+
+        let mut list = List::new();
+        sort_by_q(&mut list)?;
+        assert!(list.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn sort_list_by_q_errors() -> Result<(), Box<dyn Error>> {
+        let mut list = Parser::parse_list("a;q=0.3, b;q=2".as_bytes())?;
+        assert_eq!(
+            Err("q_value: q parameter is out of range"),
+            sort_by_q(&mut list)
+        );
+        assert_eq!("a;q=0.3, b;q=2", list.serialize_value()?);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_weighted_list() -> Result<(), Box<dyn Error>> {
+        let weighted = parse_weighted("a;q=0.3, \"b\";x, c;q=1;y, d;q=0.7, e;q=0.3".as_bytes())?;
+        let expected = vec![
+            (BareItem::String("b".to_owned()), Decimal::ONE),
+            (BareItem::Token("c".to_owned()), Decimal::ONE),
+            (BareItem::Token("d".to_owned()), Decimal::from_str("0.7")?),
+            (BareItem::Token("a".to_owned()), Decimal::from_str("0.3")?),
+            (BareItem::Token("e".to_owned()), Decimal::from_str("0.3")?),
+        ];
+        assert_eq!(expected, weighted);
+
+        assert!(parse_weighted("".as_bytes())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_weighted_list_errors() {
+        assert_eq!(
+            Err("parse_weighted: list member is not an item"),
+            parse_weighted("a, (b c);q=0.5".as_bytes())
+        );
+        assert_eq!(
+            Err("q_value: q parameter is not a number"),
+            parse_weighted("a;q=high".as_bytes())
+        );
+        assert_eq!(
+            Err("parse_list: trailing comma"),
+            parse_weighted("a;q=0.5,".as_bytes())
+        );
+    }
+}
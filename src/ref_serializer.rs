@@ -1,6 +1,7 @@
 use crate::serializer::Serializer;
 use crate::{RefBareItem, SFVResult};
-use std::marker::PhantomData;
+use alloc::string::String;
+use core::marker::PhantomData;
 
 /// Serializes `Item` field value components incrementally.
 /// ```
@@ -190,6 +191,44 @@ impl<'a> RefDictSerializer<'a> {
     }
 }
 
+/// Serializes `Dictionary` members produced by an iterator, without collecting them first.
+/// Members are appended to `buffer` one at a time.
+/// Fails if the iterator yields no members, same as serializing an empty `Dictionary`.
+/// ```
+/// use sfv::{serialize_dict_from_iter, RefBareItem};
+///
+/// let mut serialized_dict = String::new();
+/// let names = ["a", "b", "c"];
+/// let members = names
+///     .iter()
+///     .zip(0..)
+///     .map(|(name, i)| (*name, RefBareItem::Boolean(i % 2 == 0)));
+/// serialize_dict_from_iter(members, &mut serialized_dict).unwrap();
+/// assert_eq!(serialized_dict, "a, b=?0, c");
+/// ```
+pub fn serialize_dict_from_iter<'b, I>(members: I, buffer: &mut String) -> SFVResult<()>
+where
+    I: IntoIterator<Item = (&'b str, RefBareItem<'b>)>,
+{
+    let mut is_empty = true;
+    for (name, value) in members {
+        if !is_empty {
+            buffer.push_str(", ");
+        }
+        Serializer::serialize_key(name, buffer)?;
+        if value != RefBareItem::Boolean(true) {
+            buffer.push('=');
+            Serializer::serialize_ref_bare_item(&value, buffer)?;
+        }
+        is_empty = false;
+    }
+
+    if is_empty {
+        return Err("serialize_dictionary: serializing empty field is not allowed");
+    }
+    Ok(())
+}
+
 /// Used by `RefItemSerializer`, `RefListSerializer`, `RefDictSerializer` to serialize `InnerList`.
 #[derive(Debug)]
 pub struct RefInnerListSerializer<'a, T> {
@@ -231,13 +270,13 @@ pub trait Container<'a> {
 }
 
 impl<'a> Container<'a> for RefListSerializer<'a> {
-    fn new(buffer: &mut String) -> RefListSerializer {
+    fn new(buffer: &'a mut String) -> RefListSerializer<'a> {
         RefListSerializer { buffer }
     }
 }
 
 impl<'a> Container<'a> for RefDictSerializer<'a> {
-    fn new(buffer: &mut String) -> RefDictSerializer {
+    fn new(buffer: &'a mut String) -> RefDictSerializer<'a> {
         RefDictSerializer { buffer }
     }
 }
@@ -245,7 +284,7 @@ impl<'a> Container<'a> for RefDictSerializer<'a> {
 #[cfg(test)]
 mod alternative_serializer_tests {
     use super::*;
-    use crate::{Decimal, FromPrimitive};
+    use crate::{BareItem, Decimal, FromPrimitive};
 
     #[test]
     fn test_fast_serialize_item() -> SFVResult<()> {
@@ -307,4 +346,90 @@ mod alternative_serializer_tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_fast_serialize_list_each_bare_item_variant() -> SFVResult<()> {
+        let mut output = String::new();
+        let ser = RefListSerializer::new(&mut output);
+        ser.bare_item(&RefBareItem::Integer(-1))?
+            .bare_item(&RefBareItem::Decimal(Decimal::from_f64(1.5).unwrap()))?
+            .bare_item(&RefBareItem::String("str"))?
+            .bare_item(&RefBareItem::ByteSeq("hello".as_bytes()))?
+            .bare_item(&RefBareItem::Boolean(false))?
+            .bare_item(&RefBareItem::Token("tok"))?;
+        assert_eq!("-1, 1.5, \"str\", :aGVsbG8=:, ?0, tok", output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fast_serialize_dict_each_bare_item_variant() -> SFVResult<()> {
+        let mut output = String::new();
+        let ser = RefDictSerializer::new(&mut output);
+        ser.bare_item_member("a", &RefBareItem::Integer(-1))?
+            .bare_item_member("b", &RefBareItem::Decimal(Decimal::from_f64(1.5).unwrap()))?
+            .bare_item_member("c", &RefBareItem::String("str"))?
+            .bare_item_member("d", &RefBareItem::ByteSeq("hello".as_bytes()))?
+            .bare_item_member("e", &RefBareItem::Boolean(false))?
+            .bare_item_member("f", &RefBareItem::Boolean(true))?
+            .bare_item_member("g", &RefBareItem::Token("tok"))?;
+        assert_eq!(
+            "a=-1, b=1.5, c=\"str\", d=:aGVsbG8=:, e=?0, f, g=tok",
+            output
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialize_dict_from_iter() -> SFVResult<()> {
+        let mut output = String::new();
+        let members = (0..3).map(|i| match i {
+            0 => ("a", RefBareItem::Boolean(true)),
+            1 => ("b", RefBareItem::Boolean(false)),
+            _ => ("c", RefBareItem::Integer(i)),
+        });
+        serialize_dict_from_iter(members, &mut output)?;
+        assert_eq!("a, b=?0, c=2", output);
+
+        let mut output = String::new();
+        serialize_dict_from_iter(vec![("key", RefBareItem::String("str"))], &mut output)?;
+        assert_eq!("key=\"str\"", output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialize_dict_from_iter_errors() {
+        let mut output = String::new();
+        assert_eq!(
+            Err("serialize_dictionary: serializing empty field is not allowed"),
+            serialize_dict_from_iter(core::iter::empty(), &mut output)
+        );
+
+        let mut output = String::new();
+        assert_eq!(
+            Err("serialize_key: disallowed character in input"),
+            serialize_dict_from_iter(vec![("Key", RefBareItem::Integer(1))], &mut output)
+        );
+    }
+
+    #[test]
+    fn test_ref_bare_item_from_each_bare_item_variant() -> SFVResult<()> {
+        let bare_items = vec![
+            BareItem::Integer(-1),
+            BareItem::Decimal(Decimal::from_f64(1.5).unwrap()),
+            BareItem::String("str".into()),
+            BareItem::ByteSeq("hello".as_bytes().to_vec()),
+            BareItem::Boolean(false),
+            BareItem::Token("tok".into()),
+        ];
+
+        let mut output = String::new();
+        let mut ser = RefListSerializer::new(&mut output);
+        for bare_item in &bare_items {
+            let ref_bare_item = RefBareItem::from(bare_item);
+            assert_eq!(bare_item, &BareItem::from(ref_bare_item.clone()));
+            ser = ser.bare_item(&ref_bare_item)?;
+        }
+        assert_eq!("-1, 1.5, \"str\", :aGVsbG8=:, ?0, tok", output);
+        Ok(())
+    }
 }
@@ -1,8 +1,10 @@
 use crate::utils;
 use crate::{
     BareItem, Decimal, Dictionary, InnerList, Item, List, ListEntry, Parameters, RefBareItem,
-    SFVResult,
+    SFVResult, MAX_INTEGER, MIN_INTEGER,
 };
+use alloc::{format, string::String};
+use core::fmt::{self, Write};
 use data_encoding::BASE64;
 
 /// Serializes structured field value into String.
@@ -21,6 +23,92 @@ pub trait SerializeValue {
     /// );
     /// ```
     fn serialize_value(&self) -> SFVResult<String>;
+
+    /// Serializes structured field value into String.
+    /// Returns `None` for empty `List` or `Dictionary`, which can't be serialized,
+    /// meaning that the field should be omitted.
+    /// # Examples
+    /// ```
+    /// # use sfv::{List, SerializeValue};
+    ///
+    /// let list_field = List::new();
+    /// assert_eq!(list_field.serialize_value_or_empty().unwrap(), None);
+    /// ```
+    fn serialize_value_or_empty(&self) -> SFVResult<Option<String>> {
+        self.serialize_value().map(Some)
+    }
+
+    /// Computes length in bytes of serialized structured field value
+    /// without building the serialized String.
+    /// Returns the same error as `serialize_value` would if value can't be serialized.
+    /// # Examples
+    /// ```
+    /// # use sfv::{BareItem, Item, SerializeValue};
+    ///
+    /// let item = Item::new(BareItem::String("say \"hi\"".into()));
+    /// assert_eq!(item.serialized_len().unwrap(), 12);
+    /// assert_eq!(item.serialized_len().unwrap(), item.serialize_value().unwrap().len());
+    /// ```
+    fn serialized_len(&self) -> SFVResult<usize> {
+        self.serialize_value().map(|output| output.len())
+    }
+
+    /// Serializes structured field value into String, formatted according to `options`.
+    /// With default options output is the same as of `serialize_value`.
+    /// Types which don't support formatting options fall back to `serialize_value`.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializeOptions, SerializeValue};
+    ///
+    /// let dict = Parser::parse_dictionary("a=1;x;y=2, b=(1 2);z=3".as_bytes()).unwrap();
+    /// let options = SerializeOptions {
+    ///     space_after_semicolon: true,
+    ///     ..SerializeOptions::default()
+    /// };
+    /// assert_eq!(dict.serialize_value_with(&options).unwrap(), "a=1; x; y=2, b=(1 2); z=3");
+    /// ```
+    fn serialize_value_with(&self, options: &SerializeOptions) -> SFVResult<String> {
+        let _ = options;
+        self.serialize_value()
+    }
+}
+
+/// Formatting options for `SerializeValue::serialize_value_with`.
+///
+/// Default options produce canonical serialization.
+/// Other options add whitespace for readability, e.g. when printing fields in logs,
+/// or change separators between members for peers which require it.
+/// Spaces after `;` are allowed by RFC 8941 parsing algorithm,
+/// while spaces after `=` are only accepted with `ParserConfig::lenient_whitespace`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Puts a space after `;` preceding each parameter, e.g. `1; a=2`.
+    pub space_after_semicolon: bool,
+    /// Puts a space after `=` in `Dictionary` members, e.g. `a= 1`.
+    pub space_after_equals: bool,
+    /// Separator put between `List` and `Dictionary` members, `", "` by default.
+    ///
+    /// **Warning:** any other separator produces output which is not canonical RFC 8941 serialization.
+    /// Separators other than `,` surrounded by optional spaces and tabs, e.g. `;` or `|`,
+    /// produce output which RFC 8941 parsers reject altogether, including this crate's `Parser`.
+    /// Separator is emitted as is, without validation.
+    /// Use it only to talk to a peer which requires such output, e.g. `","` for a legacy system.
+    pub member_separator: &'static str,
+    /// Keeps the number of fractional digits of decimals, e.g. of those parsed with
+    /// `ParserConfig::preserve_decimal_scale`, so that `2.50` is serialized as `2.50`.
+    /// By default decimals are serialized in their canonical form without trailing zeros, e.g. `2.5`.
+    pub preserve_decimal_scale: bool,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        SerializeOptions {
+            space_after_semicolon: false,
+            space_after_equals: false,
+            member_separator: ", ",
+            preserve_decimal_scale: false,
+        }
+    }
 }
 
 impl SerializeValue for Dictionary {
@@ -29,6 +117,23 @@ impl SerializeValue for Dictionary {
         Serializer::serialize_dict(self, &mut output)?;
         Ok(output)
     }
+
+    fn serialize_value_or_empty(&self) -> SFVResult<Option<String>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        self.serialize_value().map(Some)
+    }
+
+    fn serialized_len(&self) -> SFVResult<usize> {
+        Serializer::dict_len(self)
+    }
+
+    fn serialize_value_with(&self, options: &SerializeOptions) -> SFVResult<String> {
+        let mut output = String::new();
+        Serializer::serialize_dict_with(self, options, &mut output)?;
+        Ok(output)
+    }
 }
 
 impl SerializeValue for List {
@@ -37,6 +142,23 @@ impl SerializeValue for List {
         Serializer::serialize_list(self, &mut output)?;
         Ok(output)
     }
+
+    fn serialize_value_or_empty(&self) -> SFVResult<Option<String>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        self.serialize_value().map(Some)
+    }
+
+    fn serialized_len(&self) -> SFVResult<usize> {
+        Serializer::list_len(self)
+    }
+
+    fn serialize_value_with(&self, options: &SerializeOptions) -> SFVResult<String> {
+        let mut output = String::new();
+        Serializer::serialize_list_with(self, options, &mut output)?;
+        Ok(output)
+    }
 }
 
 impl SerializeValue for Item {
@@ -45,6 +167,270 @@ impl SerializeValue for Item {
         Serializer::serialize_item(self, &mut output)?;
         Ok(output)
     }
+
+    fn serialized_len(&self) -> SFVResult<usize> {
+        Serializer::item_len(self)
+    }
+
+    fn serialize_value_with(&self, options: &SerializeOptions) -> SFVResult<String> {
+        let mut output = String::new();
+        Serializer::serialize_item_with(self, options, &mut output)?;
+        Ok(output)
+    }
+}
+
+/// Serializes structured field value into human-readable multi-line String for diagnostics.
+/// The output is not a valid structured field value and can't be parsed back.
+pub trait SerializePretty {
+    /// Serializes structured field value into String, putting each member,
+    /// inner list item and parameter on its own indented line.
+    /// Bare items that can't be serialized are shown using their `Debug` representation.
+    /// # Examples
+    /// ```
+    /// # use sfv::{Parser, SerializePretty};
+    ///
+    /// let dict = Parser::parse_dictionary("a=1;x=2, b, c=(1 tok;y)".as_bytes()).unwrap();
+    /// assert_eq!(
+    ///     dict.serialize_pretty(),
+    ///     "a=1\n  ;x=2\nb\nc=(\n  1\n  tok\n    ;y\n)"
+    /// );
+    /// ```
+    fn serialize_pretty(&self) -> String;
+}
+
+impl SerializePretty for Dictionary {
+    fn serialize_pretty(&self) -> String {
+        let mut output = String::new();
+        for (member_name, member_value) in self.iter() {
+            match member_value {
+                ListEntry::Item(item) if item.bare_item == BareItem::Boolean(true) => {
+                    output.push_str(member_name);
+                    output.push('\n');
+                    Serializer::pretty_parameters(&item.params, 1, &mut output);
+                }
+                member_value => {
+                    output.push_str(member_name);
+                    output.push('=');
+                    Serializer::pretty_list_entry(member_value, 0, &mut output);
+                }
+            }
+        }
+        output.truncate(output.trim_end().len());
+        output
+    }
+}
+
+impl SerializePretty for List {
+    fn serialize_pretty(&self) -> String {
+        let mut output = String::new();
+        for member in self.iter() {
+            Serializer::pretty_list_entry(member, 0, &mut output);
+        }
+        output.truncate(output.trim_end().len());
+        output
+    }
+}
+
+impl SerializePretty for Item {
+    fn serialize_pretty(&self) -> String {
+        let mut output = String::new();
+        Serializer::pretty_item(self, 0, &mut output);
+        output.truncate(output.trim_end().len());
+        output
+    }
+}
+
+/// Formats `Item` the same way as `serialize_value` does.
+/// Never fails, so that it's safe to use for logging: if `Item` can't be serialized,
+/// its output is best-effort and not a valid structured field value,
+/// i.e. invalid bare items are shown using their `Debug` representation and invalid keys are shown as is.
+/// Use `serialize_value` to detect such `Items`.
+/// ```
+/// # use sfv::{BareItem, Item, Parser};
+/// let item = Parser::parse_item("1;a=tok".as_bytes()).unwrap();
+/// assert_eq!(item.to_string(), "1;a=tok");
+///
+/// let item = Item::new(BareItem::Token("".into()));
+/// assert_eq!(item.to_string(), "Token(\"\")");
+/// ```
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+        Serializer::display_item(self, &mut output);
+        f.write_str(&output)
+    }
+}
+
+/// Formats `InnerList` in its parenthesized form followed by its parameters.
+/// Never fails, `InnerList` which can't be serialized is shown on a best-effort basis, same as `Item`.
+impl fmt::Display for InnerList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+        Serializer::display_inner_list(self, &mut output);
+        f.write_str(&output)
+    }
+}
+
+/// Formats `ListEntry` as a `List` member.
+/// Never fails, `ListEntry` which can't be serialized is shown on a best-effort basis, same as `Item`.
+impl fmt::Display for ListEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListEntry::Item(item) => item.fmt(f),
+            ListEntry::InnerList(inner_list) => inner_list.fmt(f),
+        }
+    }
+}
+
+/// Serializes `value` into sf-string form, i.e. quotes it and escapes `\\` and `"` characters.
+/// Useful when a structured field value is assembled by hand and only a string component is dynamic.
+/// # Examples
+/// ```
+/// # use sfv::quote_string;
+/// assert_eq!(quote_string("say \"hi\"").unwrap(), r#""say \"hi\"""#);
+/// assert!(quote_string("new\nline").is_err());
+/// ```
+pub fn quote_string(value: &str) -> SFVResult<String> {
+    let mut output = String::with_capacity(value.len() + 2);
+    Serializer::serialize_string(value, &mut output)?;
+    Ok(output)
+}
+
+/// Serializes `List` members produced by `members` into `output`, without collecting them into a `List` first.
+/// Fails if `members` is empty, as empty `List` can't be serialized, or if any member is invalid.
+/// # Examples
+/// ```
+/// # use sfv::{serialize_list_from_iter, Item, ListEntry};
+/// let mut output = String::new();
+/// let members = (1..=6)
+///     .filter(|i| i % 2 == 0)
+///     .map(|i| ListEntry::from(Item::new(i.into())));
+/// serialize_list_from_iter(members, &mut output).unwrap();
+/// assert_eq!(output, "2, 4, 6");
+/// ```
+pub fn serialize_list_from_iter<I>(members: I, output: &mut String) -> SFVResult<()>
+where
+    I: IntoIterator<Item = ListEntry>,
+{
+    let mut is_empty = true;
+    for member in members {
+        if !is_empty {
+            output.push_str(", ");
+        }
+        match member {
+            ListEntry::Item(item) => Serializer::serialize_item(&item, output)?,
+            ListEntry::InnerList(inner_list) => {
+                Serializer::serialize_inner_list(&inner_list, output)?
+            }
+        }
+        is_empty = false;
+    }
+
+    if is_empty {
+        return Err("serialize_list: serializing empty field is not allowed");
+    }
+    Ok(())
+}
+
+/// Appends `member` to `output` holding already serialized `List` members, separating them with `", "`.
+/// If `output` is empty, only `member` is written, so a `List` can be built up member by member.
+/// On error, `output` may contain partially serialized `member`.
+/// # Examples
+/// ```
+/// # use sfv::{append_list_member, BareItem, Item, ListEntry};
+/// let mut output = String::new();
+/// append_list_member(&mut output, &Item::new(1.into()).into()).unwrap();
+/// append_list_member(&mut output, &Item::new(BareItem::Token("a".into())).into()).unwrap();
+/// assert_eq!(output, "1, a");
+/// ```
+pub fn append_list_member(output: &mut String, member: &ListEntry) -> SFVResult<()> {
+    if !output.is_empty() {
+        output.push_str(", ");
+    }
+    match member {
+        ListEntry::Item(item) => Serializer::serialize_item(item, output),
+        ListEntry::InnerList(inner_list) => Serializer::serialize_inner_list(inner_list, output),
+    }
+}
+
+/// Describes which structured field value components a string can be serialized as.
+/// Returned by `classify_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrClassification {
+    /// String is a valid sf-token, e.g. `text/html`.
+    pub is_token: bool,
+    /// String is a valid key of `Dictionary` member or parameter, e.g. `max-age`.
+    pub is_key: bool,
+    /// String can be serialized as sf-string, i.e. it consists of visible ASCII characters and spaces.
+    pub is_string: bool,
+}
+
+/// Checks which structured field value components `value` can be serialized as,
+/// e.g. to prefer a token over a string where possible.
+/// # Examples
+/// ```
+/// # use sfv::classify_str;
+/// let classification = classify_str("gzip");
+/// assert!(classification.is_token && classification.is_key && classification.is_string);
+///
+/// let classification = classify_str("text/html");
+/// assert!(classification.is_token && !classification.is_key);
+///
+/// let classification = classify_str("hello world");
+/// assert!(!classification.is_token && classification.is_string);
+/// ```
+pub fn classify_str(value: &str) -> StrClassification {
+    StrClassification {
+        is_token: Serializer::validate_token(value).is_ok(),
+        is_key: Serializer::validate_key(value).is_ok(),
+        is_string: Serializer::validate_string(value).is_ok(),
+    }
+}
+
+/// Locates characters which make a string invalid as a token, key or sf-string. Returned by `locate_invalid_chars`.
+/// Each field holds the index and the value of the first offending character, or `None` if there's no such character.
+/// Index is a char index into the string. As every non-ASCII character is invalid in all three,
+/// characters preceding the first offending one are ASCII, so it's equal to the byte index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChars {
+    /// First character which is not allowed in sf-token at its position.
+    pub token: Option<(usize, char)>,
+    /// First character which is not allowed in key of `Dictionary` member or parameter at its position.
+    pub key: Option<(usize, char)>,
+    /// First character which is not allowed in sf-string, i.e. not a visible ASCII character or space.
+    pub string: Option<(usize, char)>,
+}
+
+/// Finds the first character which makes `value` invalid as a token, key or sf-string,
+/// e.g. to point at it when reporting invalid user input.
+/// Complements `classify_str`: empty `value` is not a valid token or key,
+/// though it has no offending character.
+/// # Examples
+/// ```
+/// # use sfv::locate_invalid_chars;
+/// let invalid_chars = locate_invalid_chars("text/html;q");
+/// assert_eq!(invalid_chars.token, Some((9, ';')));
+/// assert_eq!(invalid_chars.key, Some((4, '/')));
+/// assert_eq!(invalid_chars.string, None);
+/// ```
+pub fn locate_invalid_chars(value: &str) -> InvalidChars {
+    let locate = |is_allowed: fn(usize, char) -> bool| {
+        value
+            .chars()
+            .enumerate()
+            .find(|&(index, c)| !is_allowed(index, c))
+    };
+    InvalidChars {
+        token: locate(|index, c| match index {
+            0 => c.is_ascii_alphabetic() || c == '*',
+            _ => utils::is_tchar(c) || c == ':' || c == '/',
+        }),
+        key: locate(|index, c| match index {
+            0 => c.is_ascii_lowercase() || c == '*',
+            _ => c.is_ascii_lowercase() || c.is_ascii_digit() || "_-*.".contains(c),
+        }),
+        string: locate(|_, c| (' '..='~').contains(&c)),
+    }
 }
 
 /// Container serialization functions
@@ -52,15 +438,35 @@ pub(crate) struct Serializer;
 
 impl Serializer {
     pub(crate) fn serialize_item(input_item: &Item, output: &mut String) -> SFVResult<()> {
+        Self::serialize_item_with(input_item, &SerializeOptions::default(), output)
+    }
+
+    pub(crate) fn serialize_list(input_list: &List, output: &mut String) -> SFVResult<()> {
+        Self::serialize_list_with(input_list, &SerializeOptions::default(), output)
+    }
+
+    pub(crate) fn serialize_dict(input_dict: &Dictionary, output: &mut String) -> SFVResult<()> {
+        Self::serialize_dict_with(input_dict, &SerializeOptions::default(), output)
+    }
+
+    fn serialize_item_with(
+        input_item: &Item,
+        options: &SerializeOptions,
+        output: &mut String,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-item
 
-        Self::serialize_bare_item(&input_item.bare_item, output)?;
-        Self::serialize_parameters(&input_item.params, output)?;
+        Self::serialize_bare_item_with(&input_item.bare_item, options, output)?;
+        Self::serialize_parameters_with(&input_item.params, options, output)?;
         Ok(())
     }
 
     #[allow(clippy::ptr_arg)]
-    pub(crate) fn serialize_list(input_list: &List, output: &mut String) -> SFVResult<()> {
+    fn serialize_list_with(
+        input_list: &List,
+        options: &SerializeOptions,
+        output: &mut String,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-list
         if input_list.is_empty() {
             return Err("serialize_list: serializing empty field is not allowed");
@@ -69,10 +475,10 @@ impl Serializer {
         for (idx, member) in input_list.iter().enumerate() {
             match member {
                 ListEntry::Item(item) => {
-                    Self::serialize_item(item, output)?;
+                    Self::serialize_item_with(item, options, output)?;
                 }
                 ListEntry::InnerList(inner_list) => {
-                    Self::serialize_inner_list(inner_list, output)?;
+                    Self::serialize_inner_list_with(inner_list, options, output)?;
                 }
             };
 
@@ -80,13 +486,17 @@ impl Serializer {
             //      Append “,” to output.
             //      Append a single SP to output.
             if idx < input_list.len() - 1 {
-                output.push_str(", ");
+                output.push_str(options.member_separator);
             }
         }
         Ok(())
     }
 
-    pub(crate) fn serialize_dict(input_dict: &Dictionary, output: &mut String) -> SFVResult<()> {
+    fn serialize_dict_with(
+        input_dict: &Dictionary,
+        options: &SerializeOptions,
+        output: &mut String,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-dictionary
         if input_dict.is_empty() {
             return Err("serialize_dictionary: serializing empty field is not allowed");
@@ -100,15 +510,15 @@ impl Serializer {
                     // If dict member is boolean true, no need to serialize it: only its params must be serialized
                     // Otherwise serialize entire item with its params
                     if item.bare_item == BareItem::Boolean(true) {
-                        Self::serialize_parameters(&item.params, output)?;
+                        Self::serialize_parameters_with(&item.params, options, output)?;
                     } else {
-                        output.push('=');
-                        Self::serialize_item(item, output)?;
+                        Self::serialize_equals(options, output);
+                        Self::serialize_item_with(item, options, output)?;
                     }
                 }
                 ListEntry::InnerList(inner_list) => {
-                    output.push('=');
-                    Self::serialize_inner_list(inner_list, output)?;
+                    Self::serialize_equals(options, output);
+                    Self::serialize_inner_list_with(inner_list, options, output)?;
                 }
             }
 
@@ -116,13 +526,28 @@ impl Serializer {
             //      Append “,” to output.
             //      Append a single SP to output.
             if idx < input_dict.len() - 1 {
-                output.push_str(", ");
+                output.push_str(options.member_separator);
             }
         }
         Ok(())
     }
 
+    fn serialize_equals(options: &SerializeOptions, output: &mut String) {
+        output.push('=');
+        if options.space_after_equals {
+            output.push(' ');
+        }
+    }
+
     fn serialize_inner_list(input_inner_list: &InnerList, output: &mut String) -> SFVResult<()> {
+        Self::serialize_inner_list_with(input_inner_list, &SerializeOptions::default(), output)
+    }
+
+    fn serialize_inner_list_with(
+        input_inner_list: &InnerList,
+        options: &SerializeOptions,
+        output: &mut String,
+    ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-innerlist
 
         let items = &input_inner_list.items;
@@ -130,7 +555,7 @@ impl Serializer {
 
         output.push('(');
         for (idx, item) in items.iter().enumerate() {
-            Self::serialize_item(item, output)?;
+            Self::serialize_item_with(item, options, output)?;
 
             // If more values remain in inner_list, append a single SP to output
             if idx < items.len() - 1 {
@@ -138,7 +563,7 @@ impl Serializer {
             }
         }
         output.push(')');
-        Self::serialize_parameters(inner_list_parameters, output)?;
+        Self::serialize_parameters_with(inner_list_parameters, options, output)?;
         Ok(())
     }
 
@@ -152,6 +577,19 @@ impl Serializer {
         Self::serialize_ref_bare_item(&ref_bare_item, output)
     }
 
+    fn serialize_bare_item_with(
+        input_bare_item: &BareItem,
+        options: &SerializeOptions,
+        output: &mut String,
+    ) -> SFVResult<()> {
+        match input_bare_item {
+            BareItem::Decimal(value) => {
+                Self::serialize_decimal_with_scale(*value, options.preserve_decimal_scale, output)
+            }
+            _ => Self::serialize_bare_item(input_bare_item, output),
+        }
+    }
+
     pub(crate) fn serialize_ref_bare_item(
         value: &RefBareItem,
         output: &mut String,
@@ -167,14 +605,32 @@ impl Serializer {
         Ok(())
     }
 
+    #[cfg(test)]
     pub(crate) fn serialize_parameters(
         input_params: &Parameters,
         output: &mut String,
+    ) -> SFVResult<()> {
+        Self::serialize_parameters_with(input_params, &SerializeOptions::default(), output)
+    }
+
+    fn serialize_parameters_with(
+        input_params: &Parameters,
+        options: &SerializeOptions,
+        output: &mut String,
     ) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-params
 
         for (param_name, param_value) in input_params.iter() {
-            Self::serialize_ref_parameter(param_name, &param_value.to_ref_bare_item(), output)?;
+            output.push(';');
+            if options.space_after_semicolon {
+                output.push(' ');
+            }
+            Self::serialize_key(param_name, output)?;
+
+            if param_value != &BareItem::Boolean(true) {
+                output.push('=');
+                Self::serialize_bare_item_with(param_value, options, output)?;
+            }
         }
         Ok(())
     }
@@ -197,6 +653,12 @@ impl Serializer {
     pub(crate) fn serialize_key(input_key: &str, output: &mut String) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-key
 
+        Self::validate_key(input_key)?;
+        output.push_str(input_key);
+        Ok(())
+    }
+
+    pub(crate) fn validate_key(input_key: &str) -> SFVResult<()> {
         let disallowed_chars =
             |c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || "_-*.".contains(c));
 
@@ -204,63 +666,125 @@ impl Serializer {
             return Err("serialize_key: disallowed character in input");
         }
 
-        if let Some(char) = input_key.chars().next() {
-            if !(char.is_ascii_lowercase() || char == '*') {
+        match input_key.chars().next() {
+            None => return Err("serialize_key: empty key"),
+            Some(char) if !(char.is_ascii_lowercase() || char == '*') => {
                 return Err("serialize_key: first character is not lcalpha or '*'");
             }
+            _ => {}
         }
-        output.push_str(input_key);
         Ok(())
     }
 
+    pub(crate) fn validate_bare_item(value: &BareItem) -> SFVResult<()> {
+        match value {
+            BareItem::Decimal(value) => Self::validate_decimal(*value),
+            BareItem::Integer(value) => Self::validate_integer(*value),
+            BareItem::String(value) => Self::validate_string(value),
+            BareItem::Token(value) => Self::validate_token(value),
+            BareItem::ByteSeq(_) | BareItem::Boolean(_) => Ok(()),
+        }
+    }
+
     pub(crate) fn serialize_integer(value: i64, output: &mut String) -> SFVResult<()> {
         //https://httpwg.org/specs/rfc8941.html#ser-integer
 
-        let (min_int, max_int) = (-999_999_999_999_999_i64, 999_999_999_999_999_i64);
-        if !(min_int <= value && value <= max_int) {
+        Self::validate_integer(value)?;
+        write!(output, "{}", value).map_err(|_| "serialize_integer: formatting failed")?;
+        Ok(())
+    }
+
+    pub(crate) fn validate_integer(value: i64) -> SFVResult<()> {
+        if !(MIN_INTEGER..=MAX_INTEGER).contains(&value) {
             return Err("serialize_integer: integer is out of range");
         }
-        output.push_str(&value.to_string());
         Ok(())
     }
 
     pub(crate) fn serialize_decimal(value: Decimal, output: &mut String) -> SFVResult<()> {
-        // https://httpwg.org/specs/rfc8941.html#ser-decimal
+        Self::serialize_decimal_with_scale(value, false, output)
+    }
 
-        let integer_comp_length = 12;
-        let fraction_length = 3;
+    fn serialize_decimal_with_scale(
+        value: Decimal,
+        preserve_scale: bool,
+        output: &mut String,
+    ) -> SFVResult<()> {
+        // https://httpwg.org/specs/rfc8941.html#ser-decimal
 
-        let decimal = value.round_dp(fraction_length);
-        let int_comp = decimal.trunc();
-        let fract_comp = decimal.fract();
+        // Digits are written from mantissa and scale rather than with `Decimal`'s `Display`,
+        // so that only integers are formatted and no temporary buffer is allocated
+        let (is_negative, int_comp, fract_comp, scale) =
+            Self::decimal_components(value, preserve_scale)?;
 
-        // TODO: Replace with > 999_999_999_999_u64
-        if int_comp.abs().to_string().len() > integer_comp_length {
-            return Err("serialize_decimal: integer component > 12 digits");
+        if is_negative {
+            output.push('-');
         }
-
-        if fract_comp.is_zero() {
-            output.push_str(&int_comp.to_string());
-            output.push('.');
-            output.push('0');
+        if fract_comp == 0 {
+            write!(output, "{}.0", int_comp)
         } else {
-            output.push_str(&decimal.to_string());
+            write!(
+                output,
+                "{}.{:0width$}",
+                int_comp,
+                fract_comp,
+                width = scale as usize
+            )
         }
+        .map_err(|_| "serialize_decimal: formatting failed")?;
 
         Ok(())
     }
 
-    pub(crate) fn serialize_string(value: &str, output: &mut String) -> SFVResult<()> {
-        // https://httpwg.org/specs/rfc8941.html#ser-integer
+    // Splits decimal, rounded as it's serialized, into its sign, integer and fractional components
+    // and the number of fractional digits
+    fn decimal_components(
+        value: Decimal,
+        preserve_scale: bool,
+    ) -> SFVResult<(bool, u64, u64, u32)> {
+        let fraction_length = 3;
 
-        if !value.is_ascii() {
-            return Err("serialize_string: non-ascii character");
+        // Rounds half to even, as required by RFC 8941.
+        // Trailing zeros are removed, e.g. 1.50 is serialized as 1.5, unless scale is preserved,
+        // which never exceeds three digits either way
+        let mut decimal = value.round_dp(fraction_length);
+        if !preserve_scale {
+            decimal = decimal.normalize();
+        }
+        // Zero is serialized without sign, including negative values rounded to zero
+        if decimal.is_zero() {
+            decimal.set_sign_positive(true);
         }
 
-        let vchar_or_sp = |char| char == '\x7f' || ('\x00'..='\x1f').contains(&char);
-        if value.chars().any(vchar_or_sp) {
-            return Err("serialize_string: not a visible character");
+        Self::validate_decimal(decimal)?;
+
+        let scale = decimal.scale();
+        let divisor = 10_u64.pow(scale);
+        let mantissa = decimal.mantissa().unsigned_abs() as u64;
+        Ok((
+            decimal.is_sign_negative(),
+            mantissa / divisor,
+            mantissa % divisor,
+            scale,
+        ))
+    }
+
+    pub(crate) fn validate_decimal(value: Decimal) -> SFVResult<()> {
+        let integer_comp_length = 12;
+        let fraction_length = 3;
+
+        let int_comp = value.round_dp(fraction_length).trunc();
+
+        if int_comp.abs() > Decimal::from(10_i64.pow(integer_comp_length) - 1) {
+            return Err("serialize_decimal: integer component > 12 digits");
         }
+        Ok(())
+    }
+
+    pub(crate) fn serialize_string(value: &str, output: &mut String) -> SFVResult<()> {
+        // https://httpwg.org/specs/rfc8941.html#ser-string
+
+        Self::validate_string(value)?;
 
         output.push('\"');
         for char in value.chars() {
@@ -274,18 +798,38 @@ impl Serializer {
         Ok(())
     }
 
+    pub(crate) fn validate_string(value: &str) -> SFVResult<()> {
+        if !value.is_ascii() {
+            return Err("serialize_string: non-ascii character");
+        }
+
+        let vchar_or_sp = |char| char == '\x7f' || ('\x00'..='\x1f').contains(&char);
+        if value.chars().any(vchar_or_sp) {
+            return Err("serialize_string: not a visible character");
+        }
+        Ok(())
+    }
+
     pub(crate) fn serialize_token(value: &str, output: &mut String) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-token
 
+        Self::validate_token(value)?;
+        output.push_str(value);
+        Ok(())
+    }
+
+    pub(crate) fn validate_token(value: &str) -> SFVResult<()> {
         if !value.is_ascii() {
             return Err("serialize_string: non-ascii character");
         }
 
         let mut chars = value.chars();
-        if let Some(char) = chars.next() {
-            if !(char.is_ascii_alphabetic() || char == '*') {
+        match chars.next() {
+            None => return Err("serialise_token: empty token"),
+            Some(char) if !(char.is_ascii_alphabetic() || char == '*') => {
                 return Err("serialise_token: first character is not ALPHA or '*'");
             }
+            _ => {}
         }
 
         if chars
@@ -294,17 +838,26 @@ impl Serializer {
         {
             return Err("serialise_token: disallowed character");
         }
-
-        output.push_str(value);
         Ok(())
     }
 
     pub(crate) fn serialize_byte_sequence(value: &[u8], output: &mut String) -> SFVResult<()> {
         // https://httpwg.org/specs/rfc8941.html#ser-binary
 
+        // Encodes chunk by chunk into a stack buffer rather than relying on `encode_append`,
+        // so that the only allocations are those of the output growing
+        const CHUNK_LEN: usize = 48;
+        let mut encoded = [0_u8; CHUNK_LEN / 3 * 4];
+
+        output.reserve(BASE64.encode_len(value.len()) + 2);
         output.push(':');
-        let encoded = BASE64.encode(value.as_ref());
-        output.push_str(&encoded);
+        for chunk in value.chunks(CHUNK_LEN) {
+            let encoded = &mut encoded[..BASE64.encode_len(chunk.len())];
+            BASE64.encode_mut(chunk, encoded);
+            let encoded = core::str::from_utf8(encoded)
+                .map_err(|_| "serialize_byte_sequence: encoding failed")?;
+            output.push_str(encoded);
+        }
         output.push(':');
         Ok(())
     }
@@ -316,4 +869,180 @@ impl Serializer {
         output.push_str(val);
         Ok(())
     }
+
+    // Serialized length helpers, each one mirrors the corresponding serialize_* function
+    // and performs the same validation, but only counts bytes
+    fn item_len(input_item: &Item) -> SFVResult<usize> {
+        Ok(Self::bare_item_len(&input_item.bare_item)? + Self::parameters_len(&input_item.params)?)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn list_len(input_list: &List) -> SFVResult<usize> {
+        if input_list.is_empty() {
+            return Err("serialize_list: serializing empty field is not allowed");
+        }
+
+        let mut len = ", ".len() * (input_list.len() - 1);
+        for member in input_list.iter() {
+            len += Self::list_entry_len(member)?;
+        }
+        Ok(len)
+    }
+
+    fn dict_len(input_dict: &Dictionary) -> SFVResult<usize> {
+        if input_dict.is_empty() {
+            return Err("serialize_dictionary: serializing empty field is not allowed");
+        }
+
+        let mut len = ", ".len() * (input_dict.len() - 1);
+        for (member_name, member_value) in input_dict.iter() {
+            Self::validate_key(member_name)?;
+            len += member_name.len();
+
+            match member_value {
+                ListEntry::Item(item) if item.bare_item == BareItem::Boolean(true) => {
+                    len += Self::parameters_len(&item.params)?;
+                }
+                member_value => len += 1 + Self::list_entry_len(member_value)?,
+            }
+        }
+        Ok(len)
+    }
+
+    fn list_entry_len(member: &ListEntry) -> SFVResult<usize> {
+        match member {
+            ListEntry::Item(item) => Self::item_len(item),
+            ListEntry::InnerList(inner_list) => {
+                let items = &inner_list.items;
+                let mut len = "()".len() + items.len().saturating_sub(1);
+                for item in items.iter() {
+                    len += Self::item_len(item)?;
+                }
+                Ok(len + Self::parameters_len(&inner_list.params)?)
+            }
+        }
+    }
+
+    fn parameters_len(input_params: &Parameters) -> SFVResult<usize> {
+        let mut len = 0;
+        for (param_name, param_value) in input_params.iter() {
+            Self::validate_key(param_name)?;
+            len += 1 + param_name.len();
+
+            if param_value != &BareItem::Boolean(true) {
+                len += 1 + Self::bare_item_len(param_value)?;
+            }
+        }
+        Ok(len)
+    }
+
+    fn bare_item_len(input_bare_item: &BareItem) -> SFVResult<usize> {
+        match input_bare_item {
+            BareItem::Boolean(_) => Ok("?1".len()),
+            BareItem::String(value) => {
+                Self::validate_string(value)?;
+                let escaped = value.chars().filter(|c| *c == '\\' || *c == '\"').count();
+                Ok(value.len() + escaped + 2)
+            }
+            BareItem::ByteSeq(value) => Ok(BASE64.encode_len(value.len()) + 2),
+            BareItem::Token(value) => {
+                Self::validate_token(value)?;
+                Ok(value.len())
+            }
+            BareItem::Integer(value) => {
+                Self::validate_integer(*value)?;
+                let sign_len = if *value < 0 { 1 } else { 0 };
+                let digits_len = value.unsigned_abs().checked_ilog10().unwrap_or(0) + 1;
+                Ok(sign_len + digits_len as usize)
+            }
+            BareItem::Decimal(value) => {
+                let (is_negative, int_comp, fract_comp, scale) =
+                    Self::decimal_components(*value, false)?;
+                let sign_len = if is_negative { 1 } else { 0 };
+                let int_len = int_comp.checked_ilog10().unwrap_or(0) + 1;
+                // Integral decimals are serialized with a single fractional zero
+                let fract_len = if fract_comp == 0 { 1 } else { scale };
+                Ok(sign_len + int_len as usize + 1 + fract_len as usize)
+            }
+        }
+    }
+
+    // Display helpers, which follow serialization of valid values,
+    // but never fail and show invalid bare items and keys the same way as pretty serialization does
+    fn display_item(item: &Item, output: &mut String) {
+        Self::pretty_bare_item(&item.bare_item, output);
+        Self::display_parameters(&item.params, output);
+    }
+
+    fn display_inner_list(inner_list: &InnerList, output: &mut String) {
+        output.push('(');
+        for (idx, item) in inner_list.items.iter().enumerate() {
+            if idx > 0 {
+                output.push(' ');
+            }
+            Self::display_item(item, output);
+        }
+        output.push(')');
+        Self::display_parameters(&inner_list.params, output);
+    }
+
+    fn display_parameters(params: &Parameters, output: &mut String) {
+        for (param_name, param_value) in params.iter() {
+            output.push(';');
+            output.push_str(param_name);
+            if param_value != &BareItem::Boolean(true) {
+                output.push('=');
+                Self::pretty_bare_item(param_value, output);
+            }
+        }
+    }
+
+    // Pretty serialization helpers, each entry is written at the current indentation level
+    // and is followed by a new line
+    fn pretty_list_entry(member: &ListEntry, indent: usize, output: &mut String) {
+        match member {
+            ListEntry::Item(item) => Self::pretty_item(item, indent, output),
+            ListEntry::InnerList(inner_list) => {
+                output.push_str("(\n");
+                for item in inner_list.items.iter() {
+                    Self::pretty_indent(indent + 1, output);
+                    Self::pretty_item(item, indent + 1, output);
+                }
+                Self::pretty_indent(indent, output);
+                output.push_str(")\n");
+                Self::pretty_parameters(&inner_list.params, indent + 1, output);
+            }
+        }
+    }
+
+    fn pretty_item(item: &Item, indent: usize, output: &mut String) {
+        Self::pretty_bare_item(&item.bare_item, output);
+        output.push('\n');
+        Self::pretty_parameters(&item.params, indent + 1, output);
+    }
+
+    fn pretty_parameters(params: &Parameters, indent: usize, output: &mut String) {
+        for (param_name, param_value) in params.iter() {
+            Self::pretty_indent(indent, output);
+            output.push(';');
+            output.push_str(param_name);
+            if param_value != &BareItem::Boolean(true) {
+                output.push('=');
+                Self::pretty_bare_item(param_value, output);
+            }
+            output.push('\n');
+        }
+    }
+
+    fn pretty_bare_item(bare_item: &BareItem, output: &mut String) {
+        let mut serialized = String::new();
+        match Self::serialize_bare_item(bare_item, &mut serialized) {
+            Ok(()) => output.push_str(&serialized),
+            Err(_) => output.push_str(&format!("{:?}", bare_item)),
+        }
+    }
+
+    fn pretty_indent(indent: usize, output: &mut String) {
+        output.push_str(&"  ".repeat(indent));
+    }
 }
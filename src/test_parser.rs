@@ -1,6 +1,13 @@
+use crate::text::FieldValue;
 use crate::FromStr;
-use crate::{BareItem, Decimal, Dictionary, InnerList, Item, List, Num, Parameters};
-use crate::{ParseMore, ParseValue, Parser};
+use crate::{BareItem, Decimal, Dictionary, InnerList, Item, List, ListEntry, Num, Parameters};
+use crate::{
+    Canonicality, DictionaryVisitor, ParseMore, ParseValue, Parser, ParserConfig, ReusableParser,
+    VisitFlow,
+};
+use crate::{SerializeOptions, SerializeValue};
+use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::iter::FromIterator;
 
@@ -38,6 +45,42 @@ fn parse_errors() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn parse_empty_input() -> Result<(), Box<dyn Error>> {
+    // Empty List and Dictionary are valid, e.g. for a missing field
+    assert_eq!(List::new(), Parser::parse_list("".as_bytes())?);
+    assert_eq!(List::new(), Parser::parse_list("   ".as_bytes())?);
+    assert_eq!(
+        Dictionary::default(),
+        Parser::parse_dictionary("".as_bytes())?
+    );
+    assert_eq!(
+        Dictionary::default(),
+        Parser::parse_dictionary("   ".as_bytes())?
+    );
+
+    // Item can't be empty
+    assert_eq!(
+        Err("parse_bare_item: empty item"),
+        Parser::parse_item("".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: empty item"),
+        Parser::parse_item("   ".as_bytes())
+    );
+
+    // Parsed empty containers can't be serialized back
+    assert_eq!(
+        Err("serialize_list: serializing empty field is not allowed"),
+        Parser::parse_list("".as_bytes())?.serialize_value()
+    );
+    assert_eq!(
+        Err("serialize_dictionary: serializing empty field is not allowed"),
+        Parser::parse_dictionary("".as_bytes())?.serialize_value()
+    );
+    Ok(())
+}
+
 #[test]
 fn parse_list_of_numbers() -> Result<(), Box<dyn Error>> {
     let mut input = "1,42".chars().peekable();
@@ -170,11 +213,38 @@ fn parse_inner_list_errors() -> Result<(), Box<dyn Error>> {
     let mut input = "c b); a=1".chars().peekable();
     assert_eq!(
         Err("parse_inner_list: input does not start with '('"),
-        Parser::parse_inner_list(&mut input)
+        Parser::parse_inner_list(&mut input, &ParserConfig::default())
+    );
+
+    // Parsing stops at the nested '(', leaving it unconsumed
+    let mut input = "((1))".chars().peekable();
+    assert_eq!(
+        Err("parse_inner_list: nested inner list"),
+        Parser::parse_inner_list(&mut input, &ParserConfig::default())
     );
+    assert_eq!("(1))", input.collect::<String>());
+
+    let mut input = "(1 (2))".chars().peekable();
+    assert_eq!(
+        Err("parse_inner_list: nested inner list"),
+        Parser::parse_inner_list(&mut input, &ParserConfig::default())
+    );
+    assert_eq!("(2))", input.collect::<String>());
     Ok(())
 }
 
+#[test]
+fn parse_list_nested_inner_list() {
+    assert_eq!(
+        Err("parse_inner_list: nested inner list"),
+        Parser::parse_list(b"((1))")
+    );
+    assert_eq!(
+        Err("parse_inner_list: nested inner list"),
+        Parser::parse_dictionary(b"a=(1 ()), b")
+    );
+}
+
 #[test]
 fn parse_inner_list_with_param_and_spaces() -> Result<(), Box<dyn Error>> {
     let mut input = "(c b); a=1".chars().peekable();
@@ -183,7 +253,10 @@ fn parse_inner_list_with_param_and_spaces() -> Result<(), Box<dyn Error>> {
     let item1 = Item::new(BareItem::Token("c".to_owned()));
     let item2 = Item::new(BareItem::Token("b".to_owned()));
     let expected = InnerList::with_params(vec![item1, item2], inner_list_param);
-    assert_eq!(expected, Parser::parse_inner_list(&mut input)?);
+    assert_eq!(
+        expected,
+        Parser::parse_inner_list(&mut input, &ParserConfig::default())?
+    );
     Ok(())
 }
 
@@ -215,6 +288,27 @@ fn parse_item_number_with_param() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn parse_item_parts() -> Result<(), Box<dyn Error>> {
+    let (bare_item, params) = Parser::parse_item_parts("5;a=1;b=2".as_bytes())?;
+    assert_eq!(BareItem::Integer(5), bare_item);
+    let expected_params = Parameters::from_iter(vec![
+        ("a".to_owned(), BareItem::Integer(1)),
+        ("b".to_owned(), BareItem::Integer(2)),
+    ]);
+    assert_eq!(expected_params, params);
+
+    let (bare_item, params) = Parser::parse_item_parts("tok".as_bytes())?;
+    assert_eq!(BareItem::Token("tok".to_owned()), bare_item);
+    assert!(params.is_empty());
+
+    assert_eq!(
+        Err("parse_bare_item: empty item"),
+        Parser::parse_item_parts("".as_bytes())
+    );
+    Ok(())
+}
+
 #[test]
 fn parse_item_errors() -> Result<(), Box<dyn Error>> {
     assert_eq!(
@@ -227,7 +321,7 @@ fn parse_item_errors() -> Result<(), Box<dyn Error>> {
 #[test]
 fn parse_dict_empty() -> Result<(), Box<dyn Error>> {
     assert_eq!(
-        Dictionary::new(),
+        Dictionary::default(),
         Dictionary::parse(&mut "".chars().peekable())?
     );
     Ok(())
@@ -324,23 +418,23 @@ fn parse_dict_multiple_spaces() -> Result<(), Box<dyn Error>> {
 fn parse_bare_item() -> Result<(), Box<dyn Error>> {
     assert_eq!(
         BareItem::Boolean(false),
-        Parser::parse_bare_item(&mut "?0".chars().peekable())?
+        BareItem::parse(&mut "?0".chars().peekable())?
     );
     assert_eq!(
         BareItem::String("test string".to_owned()),
-        Parser::parse_bare_item(&mut "\"test string\"".chars().peekable())?
+        BareItem::parse(&mut "\"test string\"".chars().peekable())?
     );
     assert_eq!(
         BareItem::Token("*token".to_owned()),
-        Parser::parse_bare_item(&mut "*token".chars().peekable())?
+        BareItem::parse(&mut "*token".chars().peekable())?
     );
     assert_eq!(
         BareItem::ByteSeq("base_64 encoding test".to_owned().into_bytes()),
-        Parser::parse_bare_item(&mut ":YmFzZV82NCBlbmNvZGluZyB0ZXN0:".chars().peekable())?
+        BareItem::parse(&mut ":YmFzZV82NCBlbmNvZGluZyB0ZXN0:".chars().peekable())?
     );
     assert_eq!(
         BareItem::Decimal(Decimal::from_str("-3.55")?),
-        Parser::parse_bare_item(&mut "-3.55".chars().peekable())?
+        BareItem::parse(&mut "-3.55".chars().peekable())?
     );
     Ok(())
 }
@@ -349,15 +443,15 @@ fn parse_bare_item() -> Result<(), Box<dyn Error>> {
 fn parse_bare_item_errors() -> Result<(), Box<dyn Error>> {
     assert_eq!(
         Err("parse_bare_item: item type can't be identified"),
-        Parser::parse_bare_item(&mut "!?0".chars().peekable())
+        BareItem::parse(&mut "!?0".chars().peekable())
     );
     assert_eq!(
         Err("parse_bare_item: item type can't be identified"),
-        Parser::parse_bare_item(&mut "_11abc".chars().peekable())
+        BareItem::parse(&mut "_11abc".chars().peekable())
     );
     assert_eq!(
         Err("parse_bare_item: item type can't be identified"),
-        Parser::parse_bare_item(&mut "   ".chars().peekable())
+        BareItem::parse(&mut "   ".chars().peekable())
     );
     Ok(())
 }
@@ -365,11 +459,11 @@ fn parse_bare_item_errors() -> Result<(), Box<dyn Error>> {
 #[test]
 fn parse_bool() -> Result<(), Box<dyn Error>> {
     let mut input = "?0gk".chars().peekable();
-    assert_eq!(false, Parser::parse_bool(&mut input)?);
+    assert!(!Parser::parse_bool(&mut input)?);
     assert_eq!(input.collect::<String>(), "gk");
 
-    assert_eq!(false, Parser::parse_bool(&mut "?0".chars().peekable())?);
-    assert_eq!(true, Parser::parse_bool(&mut "?1".chars().peekable())?);
+    assert!(!Parser::parse_bool(&mut "?0".chars().peekable())?);
+    assert!(Parser::parse_bool(&mut "?1".chars().peekable())?);
     Ok(())
 }
 
@@ -499,25 +593,34 @@ fn parse_byte_sequence() -> Result<(), Box<dyn Error>> {
     let mut input = ":aGVsbG8:rest_of_str".chars().peekable();
     assert_eq!(
         "hello".to_owned().into_bytes(),
-        Parser::parse_byte_sequence(&mut input)?
+        Parser::parse_byte_sequence(&mut input, &ParserConfig::default())?
     );
     assert_eq!("rest_of_str", input.collect::<String>());
 
     assert_eq!(
         "hello".to_owned().into_bytes(),
-        Parser::parse_byte_sequence(&mut ":aGVsbG8:".chars().peekable())?
+        Parser::parse_byte_sequence(
+            &mut ":aGVsbG8:".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         "test_encode".to_owned().into_bytes(),
-        Parser::parse_byte_sequence(&mut ":dGVzdF9lbmNvZGU:".chars().peekable())?
+        Parser::parse_byte_sequence(
+            &mut ":dGVzdF9lbmNvZGU:".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         "new:year tree".to_owned().into_bytes(),
-        Parser::parse_byte_sequence(&mut ":bmV3OnllYXIgdHJlZQ==:".chars().peekable())?
+        Parser::parse_byte_sequence(
+            &mut ":bmV3OnllYXIgdHJlZQ==:".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         "".to_owned().into_bytes(),
-        Parser::parse_byte_sequence(&mut "::".chars().peekable())?
+        Parser::parse_byte_sequence(&mut "::".chars().peekable(), &ParserConfig::default())?
     );
     Ok(())
 }
@@ -526,15 +629,21 @@ fn parse_byte_sequence() -> Result<(), Box<dyn Error>> {
 fn parse_byte_sequence_errors() -> Result<(), Box<dyn Error>> {
     assert_eq!(
         Err("parse_byte_seq: first char is not ':'"),
-        Parser::parse_byte_sequence(&mut "aGVsbG8".chars().peekable())
+        Parser::parse_byte_sequence(&mut "aGVsbG8".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_byte_seq: invalid char in byte sequence"),
-        Parser::parse_byte_sequence(&mut ":aGVsb G8=:".chars().peekable())
+        Parser::parse_byte_sequence(
+            &mut ":aGVsb G8=:".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_byte_seq: no closing ':'"),
-        Parser::parse_byte_sequence(&mut ":aGVsbG8=".chars().peekable())
+        Parser::parse_byte_sequence(
+            &mut ":aGVsbG8=".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     Ok(())
 }
@@ -544,53 +653,65 @@ fn parse_number_int() -> Result<(), Box<dyn Error>> {
     let mut input = "-733333333332d.14".chars().peekable();
     assert_eq!(
         Num::Integer(-733333333332),
-        Parser::parse_number(&mut input)?
+        Parser::parse_number(&mut input, &ParserConfig::default())?
     );
     assert_eq!("d.14", input.collect::<String>());
 
     assert_eq!(
         Num::Integer(42),
-        Parser::parse_number(&mut "42".chars().peekable())?
+        Parser::parse_number(&mut "42".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(-42),
-        Parser::parse_number(&mut "-42".chars().peekable())?
+        Parser::parse_number(&mut "-42".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(-42),
-        Parser::parse_number(&mut "-042".chars().peekable())?
+        Parser::parse_number(&mut "-042".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(0),
-        Parser::parse_number(&mut "0".chars().peekable())?
+        Parser::parse_number(&mut "0".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(0),
-        Parser::parse_number(&mut "00".chars().peekable())?
+        Parser::parse_number(&mut "00".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(123456789012345),
-        Parser::parse_number(&mut "123456789012345".chars().peekable())?
+        Parser::parse_number(
+            &mut "123456789012345".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         Num::Integer(-123456789012345),
-        Parser::parse_number(&mut "-123456789012345".chars().peekable())?
+        Parser::parse_number(
+            &mut "-123456789012345".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         Num::Integer(2),
-        Parser::parse_number(&mut "2,3".chars().peekable())?
+        Parser::parse_number(&mut "2,3".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(4),
-        Parser::parse_number(&mut "4-2".chars().peekable())?
+        Parser::parse_number(&mut "4-2".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Integer(-999999999999999),
-        Parser::parse_number(&mut "-999999999999999".chars().peekable())?
+        Parser::parse_number(
+            &mut "-999999999999999".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         Num::Integer(999999999999999),
-        Parser::parse_number(&mut "999999999999999".chars().peekable())?
+        Parser::parse_number(
+            &mut "999999999999999".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
 
     Ok(())
@@ -601,37 +722,43 @@ fn parse_number_decimal() -> Result<(), Box<dyn Error>> {
     let mut input = "00.42 test string".chars().peekable();
     assert_eq!(
         Num::Decimal(Decimal::from_str("0.42")?),
-        Parser::parse_number(&mut input)?
+        Parser::parse_number(&mut input, &ParserConfig::default())?
     );
     assert_eq!(" test string", input.collect::<String>());
 
     assert_eq!(
         Num::Decimal(Decimal::from_str("1.5")?),
-        Parser::parse_number(&mut "1.5.4.".chars().peekable())?
+        Parser::parse_number(&mut "1.5.4.".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("1.8")?),
-        Parser::parse_number(&mut "1.8.".chars().peekable())?
+        Parser::parse_number(&mut "1.8.".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("1.7")?),
-        Parser::parse_number(&mut "1.7.0".chars().peekable())?
+        Parser::parse_number(&mut "1.7.0".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("3.14")?),
-        Parser::parse_number(&mut "3.14".chars().peekable())?
+        Parser::parse_number(&mut "3.14".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("-3.14")?),
-        Parser::parse_number(&mut "-3.14".chars().peekable())?
+        Parser::parse_number(&mut "-3.14".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("123456789012.1")?),
-        Parser::parse_number(&mut "123456789012.1".chars().peekable())?
+        Parser::parse_number(
+            &mut "123456789012.1".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
         Num::Decimal(Decimal::from_str("1234567890.112")?),
-        Parser::parse_number(&mut "1234567890.112".chars().peekable())?
+        Parser::parse_number(
+            &mut "1234567890.112".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
 
     Ok(())
@@ -642,68 +769,86 @@ fn parse_number_errors() -> Result<(), Box<dyn Error>> {
     let mut input = ":aGVsbG8:rest".chars().peekable();
     assert_eq!(
         Err("parse_number: input number does not start with a digit"),
-        Parser::parse_number(&mut input)
+        Parser::parse_number(&mut input, &ParserConfig::default())
     );
     assert_eq!(":aGVsbG8:rest", input.collect::<String>());
 
     let mut input = "-11.5555 test string".chars().peekable();
     assert_eq!(
         Err("parse_number: invalid decimal fraction length"),
-        Parser::parse_number(&mut input)
+        Parser::parse_number(&mut input, &ParserConfig::default())
     );
     assert_eq!(" test string", input.collect::<String>());
 
     assert_eq!(
         Err("parse_number: input number does not start with a digit"),
-        Parser::parse_number(&mut "--0".chars().peekable())
+        Parser::parse_number(&mut "--0".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: decimal too long, illegal position for decimal point"),
-        Parser::parse_number(&mut "1999999999999.1".chars().peekable())
+        Parser::parse_number(
+            &mut "1999999999999.1".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_number: decimal ends with '.'"),
-        Parser::parse_number(&mut "19888899999.".chars().peekable())
+        Parser::parse_number(
+            &mut "19888899999.".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_number: integer too long, length > 15"),
-        Parser::parse_number(&mut "1999999999999999".chars().peekable())
+        Parser::parse_number(
+            &mut "1999999999999999".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_number: decimal too long, length > 16"),
-        Parser::parse_number(&mut "19999999999.99991".chars().peekable())
+        Parser::parse_number(
+            &mut "19999999999.99991".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_number: input number does not start with a digit"),
-        Parser::parse_number(&mut "- 42".chars().peekable())
+        Parser::parse_number(&mut "- 42".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: input number does not start with a digit"),
-        Parser::parse_number(&mut "- 42".chars().peekable())
+        Parser::parse_number(&mut "- 42".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: decimal ends with '.'"),
-        Parser::parse_number(&mut "1..4".chars().peekable())
+        Parser::parse_number(&mut "1..4".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: input number lacks a digit"),
-        Parser::parse_number(&mut "-".chars().peekable())
+        Parser::parse_number(&mut "-".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: decimal ends with '.'"),
-        Parser::parse_number(&mut "-5. 14".chars().peekable())
+        Parser::parse_number(&mut "-5. 14".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: decimal ends with '.'"),
-        Parser::parse_number(&mut "7. 1".chars().peekable())
+        Parser::parse_number(&mut "7. 1".chars().peekable(), &ParserConfig::default())
     );
     assert_eq!(
         Err("parse_number: invalid decimal fraction length"),
-        Parser::parse_number(&mut "-7.3333333333".chars().peekable())
+        Parser::parse_number(
+            &mut "-7.3333333333".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
     assert_eq!(
         Err("parse_number: decimal too long, illegal position for decimal point"),
-        Parser::parse_number(&mut "-7333333333323.12".chars().peekable())
+        Parser::parse_number(
+            &mut "-7333333333323.12".chars().peekable(),
+            &ParserConfig::default()
+        )
     );
 
     Ok(())
@@ -716,7 +861,10 @@ fn parse_params_string() -> Result<(), Box<dyn Error>> {
         "b".to_owned(),
         BareItem::String("param_val".to_owned()),
     )]);
-    assert_eq!(expected, Parser::parse_parameters(&mut input)?);
+    assert_eq!(
+        expected,
+        Parser::parse_parameters(&mut input, &ParserConfig::default())?
+    );
     Ok(())
 }
 
@@ -727,7 +875,26 @@ fn parse_params_bool() -> Result<(), Box<dyn Error>> {
         ("b".to_owned(), BareItem::Boolean(true)),
         ("a".to_owned(), BareItem::Boolean(true)),
     ]);
-    assert_eq!(expected, Parser::parse_parameters(&mut input)?);
+    assert_eq!(
+        expected,
+        Parser::parse_parameters(&mut input, &ParserConfig::default())?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_params_explicit_bool_true() -> Result<(), Box<dyn Error>> {
+    // Explicit and implicit true values are parsed into the same representation,
+    // so such items are equal without any normalization and serialize to the implicit form
+    let explicit = Parser::parse_item("x;a=?1;b=?0".as_bytes())?;
+    let implicit = Parser::parse_item("x;a;b=?0".as_bytes())?;
+    assert_eq!(implicit, explicit);
+    assert_eq!("x;a;b=?0", explicit.serialize_value()?);
+
+    let explicit = Parser::parse_dictionary("k=?1;a=?1, l=(1;b=?1);c=?1".as_bytes())?;
+    let implicit = Parser::parse_dictionary("k;a, l=(1;b);c".as_bytes())?;
+    assert_eq!(implicit, explicit);
+    assert_eq!("k;a, l=(1;b);c", explicit.serialize_value()?);
     Ok(())
 }
 
@@ -738,7 +905,10 @@ fn parse_params_mixed_types() -> Result<(), Box<dyn Error>> {
         ("key1".to_owned(), BareItem::Boolean(false)),
         ("key2".to_owned(), Decimal::from_str("746.15")?.into()),
     ]);
-    assert_eq!(expected, Parser::parse_parameters(&mut input)?);
+    assert_eq!(
+        expected,
+        Parser::parse_parameters(&mut input, &ParserConfig::default())?
+    );
     Ok(())
 }
 
@@ -749,27 +919,36 @@ fn parse_params_with_spaces() -> Result<(), Box<dyn Error>> {
         ("key1".to_owned(), BareItem::Boolean(false)),
         ("key2".to_owned(), 11111.into()),
     ]);
-    assert_eq!(expected, Parser::parse_parameters(&mut input)?);
+    assert_eq!(
+        expected,
+        Parser::parse_parameters(&mut input, &ParserConfig::default())?
+    );
     Ok(())
 }
 
 #[test]
 fn parse_params_empty() -> Result<(), Box<dyn Error>> {
     assert_eq!(
-        Parameters::new(),
-        Parser::parse_parameters(&mut " key1=?0; key2=11111".chars().peekable())?
+        Parameters::default(),
+        Parser::parse_parameters(
+            &mut " key1=?0; key2=11111".chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     assert_eq!(
-        Parameters::new(),
-        Parser::parse_parameters(&mut "".chars().peekable())?
+        Parameters::default(),
+        Parser::parse_parameters(&mut "".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
-        Parameters::new(),
-        Parser::parse_parameters(&mut "[;a=1".chars().peekable())?
+        Parameters::default(),
+        Parser::parse_parameters(&mut "[;a=1".chars().peekable(), &ParserConfig::default())?
     );
     assert_eq!(
-        Parameters::new(),
-        Parser::parse_parameters(&mut String::new().chars().peekable())?
+        Parameters::default(),
+        Parser::parse_parameters(
+            &mut String::new().chars().peekable(),
+            &ParserConfig::default()
+        )?
     );
     Ok(())
 }
@@ -801,6 +980,39 @@ fn parse_key_errors() -> Result<(), Box<dyn Error>> {
         Err("parse_key: first character is not lcalpha or '*'"),
         Parser::parse_key(&mut "[*f=10".chars().peekable())
     );
+
+    // Uppercase first character is rejected without being consumed
+    let mut input = "Key=1".chars().peekable();
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        Parser::parse_key(&mut input)
+    );
+    assert_eq!("Key=1", input.collect::<String>());
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        Parser::parse_dictionary("Key=1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        Parser::parse_item("1;Key=1".as_bytes())
+    );
+
+    // Key ends at disallowed character, which is left for the caller to reject
+    let mut input = "k^=1".chars().peekable();
+    assert_eq!("k", Parser::parse_key(&mut input)?);
+    assert_eq!("^=1", input.collect::<String>());
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary("k^=1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary("kA=1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_item("1;k^=1".as_bytes())
+    );
     Ok(())
 }
 
@@ -813,7 +1025,7 @@ fn parse_more_list() -> Result<(), Box<dyn Error>> {
     let expected_list: List = vec![inner_list_1.into(), item3.into()];
 
     let mut parsed_header = Parser::parse_list("(1 2)".as_bytes())?;
-    let _ = parsed_header.parse_more("42".as_bytes())?;
+    parsed_header.parse_more("42".as_bytes())?;
     assert_eq!(expected_list, parsed_header);
     Ok(())
 }
@@ -832,7 +1044,7 @@ fn parse_more_dict() -> Result<(), Box<dyn Error>> {
     ]);
 
     let mut parsed_header = Parser::parse_dictionary("a=1, b;foo=*\t\t".as_bytes())?;
-    let _ = parsed_header.parse_more(" c=3".as_bytes())?;
+    parsed_header.parse_more(" c=3".as_bytes())?;
     assert_eq!(expected_dict, parsed_header);
     Ok(())
 }
@@ -848,3 +1060,1442 @@ fn parse_more_errors() -> Result<(), Box<dyn Error>> {
     assert!(parsed_list_header.is_err());
     Ok(())
 }
+
+#[test]
+fn parse_list_lines() -> Result<(), Box<dyn Error>> {
+    let item1 = Item::new(BareItem::String("a".to_owned()));
+    let item2 = Item::new(BareItem::String("b".to_owned()));
+    let inner_list = InnerList::new(vec![Item::new(1.into()), Item::new(2.into())]);
+    let expected_list: List = vec![item1.into(), item2.into(), inner_list.into()];
+
+    let lines: &[&[u8]] = &["\"a\", \"b\"".as_bytes(), "(1 2)".as_bytes()];
+    assert_eq!(expected_list, Parser::parse_list_lines(lines)?);
+
+    let lines: &[&[u8]] = &["\"a\", \"b\"".as_bytes(), "".as_bytes(), "(1 2)".as_bytes()];
+    assert_eq!(expected_list, Parser::parse_list_lines(lines)?);
+
+    let lines: &[&[u8]] = &["".as_bytes(), "  ".as_bytes()];
+    assert_eq!(List::new(), Parser::parse_list_lines(lines)?);
+    Ok(())
+}
+
+#[test]
+fn parse_list_lines_errors() -> Result<(), Box<dyn Error>> {
+    let lines: &[&[u8]] = &["\"a\",".as_bytes(), "(1 2)".as_bytes()];
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        Parser::parse_list_lines(lines)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_decimal_integer_component_length() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Item::new(Decimal::from_str("123456789012.0")?.into()),
+        Parser::parse_item("123456789012.0".as_bytes())?
+    );
+    assert_eq!(
+        Item::new(Decimal::from_str("-123456789012.123")?.into()),
+        Parser::parse_item("-123456789012.123".as_bytes())?
+    );
+    assert_eq!(
+        Err("parse_number: decimal too long, illegal position for decimal point"),
+        Parser::parse_item("1234567890123.0".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_number: decimal too long, illegal position for decimal point"),
+        Parser::parse_item("-1234567890123.0".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_byte_sequence_alphabet_and_padding() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        "hello".to_owned().into_bytes(),
+        Parser::parse_byte_sequence(
+            &mut ":aGVsbG8=:".chars().peekable(),
+            &ParserConfig::default()
+        )?
+    );
+    // Missing padding is tolerated, see https://httpwg.org/specs/rfc8941.html#parse-binary
+    assert_eq!(
+        "hello".to_owned().into_bytes(),
+        Parser::parse_byte_sequence(
+            &mut ":aGVsbG8:".chars().peekable(),
+            &ParserConfig::default()
+        )?
+    );
+    assert_eq!(
+        Err("parse_byte_seq: url-safe base64 alphabet is not allowed"),
+        Parser::parse_byte_sequence(
+            &mut ":aGVs_G8=:".chars().peekable(),
+            &ParserConfig::default()
+        )
+    );
+    assert_eq!(
+        Err("parse_byte_seq: url-safe base64 alphabet is not allowed"),
+        Parser::parse_byte_sequence(&mut ":_-Ah:".chars().peekable(), &ParserConfig::default())
+    );
+    assert_eq!(
+        Err("parse_byte_seq: invalid padding"),
+        Parser::parse_byte_sequence(
+            &mut ":aGV=sbG8:".chars().peekable(),
+            &ParserConfig::default()
+        )
+    );
+    assert_eq!(
+        Err("parse_byte_seq: invalid padding"),
+        Parser::parse_byte_sequence(
+            &mut ":=aGVsbG8=:".chars().peekable(),
+            &ParserConfig::default()
+        )
+    );
+    assert_eq!(
+        Err("parse_byte_seq: invalid padding"),
+        Parser::parse_byte_sequence(
+            &mut ":aGVsbG8==:".chars().peekable(),
+            &ParserConfig::default()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn decode_byte_seq_strict() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        "hello".as_bytes(),
+        Parser::decode_byte_seq_strict("aGVsbG8=".as_bytes())?
+    );
+    assert_eq!(
+        Err("decode_byte_seq_strict: decoding error"),
+        Parser::decode_byte_seq_strict("aGVsbG8".as_bytes())
+    );
+    assert_eq!(
+        Err("decode_byte_seq_strict: url-safe base64 alphabet is not allowed"),
+        Parser::decode_byte_seq_strict("aGVs_G8=".as_bytes())
+    );
+    assert_eq!(
+        Err("decode_byte_seq_strict: decoding error"),
+        Parser::decode_byte_seq_strict("iZ==".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_integer_range_boundaries() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Num::Integer(999_999_999_999_999),
+        Parser::parse_number(
+            &mut "999999999999999".chars().peekable(),
+            &ParserConfig::default()
+        )?
+    );
+    assert_eq!(
+        Num::Integer(-999_999_999_999_999),
+        Parser::parse_number(
+            &mut "-999999999999999".chars().peekable(),
+            &ParserConfig::default()
+        )?
+    );
+
+    let mut input = "1000000000000000;a".chars().peekable();
+    assert_eq!(
+        Err("parse_number: integer too long, length > 15"),
+        Parser::parse_number(&mut input, &ParserConfig::default())
+    );
+    assert_eq!(";a", input.collect::<String>());
+
+    let mut input = "-1000000000000000".chars().peekable();
+    assert_eq!(
+        Err("parse_number: integer too long, length > 15"),
+        Parser::parse_number(&mut input, &ParserConfig::default())
+    );
+    assert_eq!("", input.collect::<String>());
+
+    // Out of range integer is reported as such, not as a malformed bare item
+    assert_eq!(
+        Err("parse_number: integer too long, length > 15"),
+        Parser::parse_item("9999999999999999".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_number: input number does not start with a digit"),
+        Parser::parse_item("-a".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_number_strict() -> Result<(), Box<dyn Error>> {
+    let config = Parser::strict();
+    assert_eq!(
+        Num::Integer(0),
+        Parser::parse_number(&mut "0".chars().peekable(), &config)?
+    );
+    assert_eq!(
+        Num::Integer(-10),
+        Parser::parse_number(&mut "-10".chars().peekable(), &config)?
+    );
+    assert_eq!(
+        Num::Decimal(Decimal::from_str("0.5")?),
+        Parser::parse_number(&mut "0.5".chars().peekable(), &config)?
+    );
+    assert_eq!(
+        Num::Decimal(Decimal::from_str("1.0")?),
+        Parser::parse_number(&mut "1.0".chars().peekable(), &config)?
+    );
+    assert_eq!(
+        Num::Decimal(Decimal::from_str("10.205")?),
+        Parser::parse_number(&mut "10.205".chars().peekable(), &config)?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_number_strict_errors() -> Result<(), Box<dyn Error>> {
+    let config = Parser::strict();
+
+    // Error is reported at the first digit following leading zero
+    let mut input = "05;a".chars().peekable();
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::parse_number(&mut input, &config)
+    );
+    assert_eq!("5;a", input.collect::<String>());
+
+    let mut input = "-007".chars().peekable();
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::parse_number(&mut input, &config)
+    );
+    assert_eq!("07", input.collect::<String>());
+
+    let mut input = "00.5".chars().peekable();
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::parse_number(&mut input, &config)
+    );
+    assert_eq!("0.5", input.collect::<String>());
+
+    // Error is reported after the decimal with trailing zeros
+    let mut input = "1.230 a".chars().peekable();
+    assert_eq!(
+        Err("parse_number: trailing zeros in fractional component are not allowed"),
+        Parser::parse_number(&mut input, &config)
+    );
+    assert_eq!(" a", input.collect::<String>());
+
+    assert_eq!(
+        Err("parse_number: trailing zeros in fractional component are not allowed"),
+        Parser::parse_number(&mut "1.00".chars().peekable(), &config)
+    );
+
+    // Leading '+' is not allowed by RFC 8941 in any mode
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        config.parse_item("+5".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        Parser::parse_item("+5".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_strict_config() -> Result<(), Box<dyn Error>> {
+    assert_eq!(Item::new(5.into()), Parser::parse_item("05".as_bytes())?);
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::strict().parse_item("05".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_number: trailing zeros in fractional component are not allowed"),
+        Parser::strict().parse_list("1, 2.50".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::strict().parse_dictionary("a=1;b=01".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_decimal_scale() -> Result<(), Box<dyn Error>> {
+    let preserving = ParserConfig {
+        preserve_decimal_scale: true,
+        ..ParserConfig::default()
+    };
+
+    let preserving_options = SerializeOptions {
+        preserve_decimal_scale: true,
+        ..SerializeOptions::default()
+    };
+
+    let item = Parser::parse_item("2.50".as_bytes())?;
+    assert_eq!("2.5", item.serialize_value()?);
+    assert_eq!("2.5", item.serialize_value_with(&preserving_options)?);
+    let item = preserving.parse_item("2.50".as_bytes())?;
+    assert_eq!("2.50", item.serialize_value_with(&preserving_options)?);
+    // Serialization is canonical unless scale is preserved explicitly
+    assert_eq!("2.5", item.serialize_value()?);
+
+    let item = Parser::parse_item("-2.500".as_bytes())?;
+    assert_eq!("-2.5", item.serialize_value()?);
+    let item = preserving.parse_item("1;a=-2.500".as_bytes())?;
+    assert_eq!(
+        "1;a=-2.500",
+        item.serialize_value_with(&preserving_options)?
+    );
+
+    let item = Parser::parse_item("2.000".as_bytes())?;
+    assert_eq!("2.0", item.serialize_value()?);
+    // Zero fractional component is always serialized as a single digit
+    let item = preserving.parse_item("2.000".as_bytes())?;
+    assert_eq!("2.0", item.serialize_value_with(&preserving_options)?);
+
+    // Scale doesn't affect comparison
+    assert_eq!(
+        Parser::parse_item("2.50".as_bytes())?,
+        preserving.parse_item("2.50".as_bytes())?
+    );
+
+    assert_eq!(
+        Err("parse_number: invalid decimal fraction length"),
+        preserving.parse_item("2.5000".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_number_non_canonical_forms() -> Result<(), Box<dyn Error>> {
+    // Leading zeros are allowed by RFC 8941 parsing algorithm, strict config rejects them
+    assert_eq!(Item::new(1.into()), Parser::parse_item("01".as_bytes())?);
+    assert_eq!(
+        Err("parse_number: leading zeros are not allowed"),
+        Parser::strict().parse_item("01".as_bytes())
+    );
+
+    // Leading '+' is not a part of sf-integer or sf-decimal grammar
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        Parser::parse_item("+1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        Parser::strict().parse_item("+1.5".as_bytes())
+    );
+
+    // Negative zero is zero
+    let item = Parser::parse_item("-0".as_bytes())?;
+    assert_eq!(Item::new(0.into()), item);
+    assert_eq!("0", item.serialize_value()?);
+    let item = Parser::parse_item("-0.0".as_bytes())?;
+    assert_eq!(Item::new(BareItem::Decimal(Decimal::ZERO)), item);
+
+    // Trailing zeros in fractional component are accepted and canonicalized
+    let item = Parser::parse_item("1.10".as_bytes())?;
+    assert_eq!(
+        Item::new(BareItem::Decimal(Decimal::from_str("1.1")?)),
+        item
+    );
+    assert_eq!("1.1", item.serialize_value()?);
+    assert_eq!(
+        Err("parse_number: trailing zeros in fractional component are not allowed"),
+        Parser::strict().parse_item("1.230".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_bare_item_from_bytes() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        BareItem::Integer(5),
+        Parser::parse_bare_item("5".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::Token("foo".to_owned()),
+        Parser::parse_bare_item("  foo ".as_bytes())?
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_item("foo bar".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: empty item"),
+        Parser::parse_bare_item("".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_bare_item_each_type() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        BareItem::Integer(-42),
+        Parser::parse_bare_item("-42".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::Decimal(Decimal::from_str("4.5")?),
+        Parser::parse_bare_item("4.5".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::String("foo bar".to_owned()),
+        Parser::parse_bare_item("\"foo bar\"".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::Token("*foo/bar".to_owned()),
+        Parser::parse_bare_item("*foo/bar".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::ByteSeq("hello".to_owned().into_bytes()),
+        Parser::parse_bare_item(":aGVsbG8=:".as_bytes())?
+    );
+    assert_eq!(
+        BareItem::Boolean(false),
+        Parser::parse_bare_item("?0".as_bytes())?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_bare_item_rejects_parameters() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_item("5;x=1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_item("\"foo\";a".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_item("1, 2".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_string_escapes() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        "\\".to_owned(),
+        Parser::parse_string(&mut "\"\\\\\"".chars().peekable())?
+    );
+    assert_eq!(
+        "\"".to_owned(),
+        Parser::parse_string(&mut "\"\\\"\"".chars().peekable())?
+    );
+    assert_eq!(
+        "a\\\"b".to_owned(),
+        Parser::parse_string(&mut "\"a\\\\\\\"b\"".chars().peekable())?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_string_escapes_errors() -> Result<(), Box<dyn Error>> {
+    // Only '\' and '"' can be escaped
+    let mut input = "\"\\a\" rest".chars().peekable();
+    assert_eq!(
+        Err("parse_string: disallowed character after '\\'"),
+        Parser::parse_string(&mut input)
+    );
+    assert_eq!("\" rest", input.collect::<String>());
+
+    assert_eq!(
+        Err("parse_string: disallowed character after '\\'"),
+        Parser::parse_string(&mut "\"\\ \"".chars().peekable())
+    );
+    assert_eq!(
+        Err("parse_string: disallowed character after '\\'"),
+        Parser::parse_string(&mut "\"\\'\"".chars().peekable())
+    );
+
+    // Backslash before closing quote escapes it, so the string is not terminated
+    assert_eq!(
+        Err("parse_string: no closing '\"'"),
+        Parser::parse_string(&mut "\"abc\\\"".chars().peekable())
+    );
+    assert_eq!(
+        Err("parse_string: last input character is '\\'"),
+        Parser::parse_string(&mut "\"abc\\".chars().peekable())
+    );
+    Ok(())
+}
+
+// Records every visited component, optionally stopping at the given key
+#[derive(Default)]
+struct RecordingVisitor {
+    events: Vec<String>,
+    stop_at_key: Option<&'static str>,
+}
+
+impl DictionaryVisitor for RecordingVisitor {
+    fn on_key(&mut self, key: &str) -> VisitFlow {
+        self.events.push(format!("key {}", key));
+        match self.stop_at_key {
+            Some(stop_key) if stop_key == key => VisitFlow::Stop,
+            _ => VisitFlow::Continue,
+        }
+    }
+
+    fn on_item(&mut self, bare_item: &BareItem) -> VisitFlow {
+        self.events.push(format!("item {:?}", bare_item));
+        VisitFlow::Continue
+    }
+
+    fn on_inner_list_start(&mut self) -> VisitFlow {
+        self.events.push("(".to_owned());
+        VisitFlow::Continue
+    }
+
+    fn on_inner_list_end(&mut self) -> VisitFlow {
+        self.events.push(")".to_owned());
+        VisitFlow::Continue
+    }
+
+    fn on_parameter(&mut self, key: &str, value: &BareItem) -> VisitFlow {
+        self.events.push(format!("param {} {:?}", key, value));
+        VisitFlow::Continue
+    }
+}
+
+#[test]
+fn parse_dictionary_visit() -> Result<(), Box<dyn Error>> {
+    let mut visitor = RecordingVisitor::default();
+    Parser::parse_dictionary_visit(
+        " a=1;x, b, c=(tok \"s\";y=2);z=?0 ".as_bytes(),
+        &mut visitor,
+    )?;
+    let expected = vec![
+        "key a",
+        "item Integer(1)",
+        "param x Boolean(true)",
+        "key b",
+        "item Boolean(true)",
+        "key c",
+        "(",
+        "item Token(\"tok\")",
+        "item String(\"s\")",
+        "param y Integer(2)",
+        ")",
+        "param z Boolean(false)",
+    ];
+    assert_eq!(expected, visitor.events);
+
+    let mut visitor = RecordingVisitor::default();
+    Parser::parse_dictionary_visit("".as_bytes(), &mut visitor)?;
+    assert!(visitor.events.is_empty());
+    Ok(())
+}
+
+#[test]
+fn parse_dictionary_visit_stop() -> Result<(), Box<dyn Error>> {
+    // Input after the stop is neither visited nor validated
+    let mut visitor = RecordingVisitor {
+        stop_at_key: Some("b"),
+        ..RecordingVisitor::default()
+    };
+    Parser::parse_dictionary_visit("a=1, b=2, c=(1 2), !invalid".as_bytes(), &mut visitor)?;
+    assert_eq!(vec!["key a", "item Integer(1)", "key b"], visitor.events);
+    Ok(())
+}
+
+#[test]
+fn parse_dictionary_visit_errors() -> Result<(), Box<dyn Error>> {
+    let mut visitor = RecordingVisitor::default();
+    assert_eq!(
+        Err("parse_dict: trailing comma"),
+        Parser::parse_dictionary_visit("a=1, ".as_bytes(), &mut visitor)
+    );
+    assert_eq!(
+        Err("parse_inner_list: the end of the inner list was not found"),
+        Parser::parse_dictionary_visit("a=(1 2".as_bytes(), &mut visitor)
+    );
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary_visit("a=1 b".as_bytes(), &mut visitor)
+    );
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Parser::parse_dictionary_visit("a=\"\u{1f439}\"".as_bytes(), &mut visitor)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_string_disallowed_characters() -> Result<(), Box<dyn Error>> {
+    // Input is left positioned at the offending character
+    let mut input = "\"foo\x01bar\"".chars().peekable();
+    assert_eq!(
+        Err("parse_string: not a visible character"),
+        Parser::parse_string(&mut input)
+    );
+    assert_eq!("\x01bar\"", input.collect::<String>());
+
+    let mut input = "\"f\u{e9}\u{e8}o\"".chars().peekable();
+    assert_eq!(
+        Err("parse_string: non-ascii character"),
+        Parser::parse_string(&mut input)
+    );
+    assert_eq!("\u{e9}\u{e8}o\"", input.collect::<String>());
+
+    let mut input = "\"tab\there\"".chars().peekable();
+    assert_eq!(
+        Err("parse_string: not a visible character"),
+        Parser::parse_string(&mut input)
+    );
+    assert_eq!("\there\"", input.collect::<String>());
+
+    // Non-ascii bytes are rejected before parsing when input is given as bytes
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Parser::parse_item(b"\"f\xc3\xa9o\"")
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_item_try_from() -> Result<(), Box<dyn Error>> {
+    let expected = Parser::parse_item("\"foo\";a=1".as_bytes())?;
+    assert_eq!(expected, Item::try_from("\"foo\";a=1")?);
+    assert_eq!(expected, Item::try_from("\"foo\";a=1".to_owned())?);
+    assert_eq!(expected, Item::try_from(&b"\"foo\";a=1"[..])?);
+
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Item::try_from("1, 2")
+    );
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Item::try_from(&b"\xc3\xa9"[..])
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_matches_parse() -> Result<(), Box<dyn Error>> {
+    let inputs = vec![
+        "",
+        "1",
+        "1;a=2;b",
+        "\"foo\" ",
+        "a=1, b=(1 2);x, c",
+        "(1 2), tok;a=:YQ==:, ?0",
+        "a=1,",
+        "1, 2,",
+        "(1 2",
+        "a=1 b",
+        "1 2",
+        "\"unterminated",
+        "a=?2",
+        "A=1",
+        "1;A=2",
+        "\u{e9}",
+        ":YWJjZA:",
+        ":YWJjZ:",
+        ":YQ=:",
+        ":Y=Q=:",
+        ":YQ-_:",
+        ":Y*Q=:",
+        ":YQ==",
+        "a=((1))",
+        "a=1, a=2",
+    ];
+    for input in inputs.iter() {
+        let input_bytes = input.as_bytes();
+        assert_eq!(
+            Parser::parse_item(input_bytes).map(|_| ()),
+            Parser::validate_item(input_bytes),
+            "item: {}",
+            input
+        );
+        assert_eq!(
+            Parser::parse_list(input_bytes).map(|_| ()),
+            Parser::validate_list(input_bytes),
+            "list: {}",
+            input
+        );
+        assert_eq!(
+            Parser::parse_dictionary(input_bytes).map(|_| ()),
+            Parser::validate_dictionary(input_bytes),
+            "dictionary: {}",
+            input
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn validate_with_config_matches_parse() -> Result<(), Box<dyn Error>> {
+    let configs = [
+        Parser::strict(),
+        Parser::lenient(),
+        ParserConfig {
+            reject_duplicate_keys: true,
+            max_byte_seq_len: Some(2),
+            ..ParserConfig::default()
+        },
+    ];
+    let inputs = [
+        "a = 1 ;x, b=(1 2) ;\ty",
+        "05, 1.50, 1.5",
+        "a=1, b=2,",
+        "a=1, b, a=2",
+        "a=1, b, a=(1",
+        "a=:YWI=:, b=:YWJj:",
+    ];
+    for config in configs.iter() {
+        for input in inputs.iter() {
+            let input_bytes = input.as_bytes();
+            assert_eq!(
+                config.parse_item(input_bytes).map(|_| ()),
+                config.validate_item(input_bytes),
+                "item: {} {:?}",
+                input,
+                config
+            );
+            assert_eq!(
+                config.parse_list(input_bytes).map(|_| ()),
+                config.validate_list(input_bytes),
+                "list: {} {:?}",
+                input,
+                config
+            );
+            assert_eq!(
+                config.parse_dictionary(input_bytes).map(|_| ()),
+                config.validate_dictionary(input_bytes),
+                "dictionary: {} {:?}",
+                input,
+                config
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_dictionary_visit_with_config() -> Result<(), Box<dyn Error>> {
+    let config = ParserConfig {
+        allow_trailing_comma: true,
+        lenient_whitespace: true,
+        ..ParserConfig::default()
+    };
+    let mut visitor = RecordingVisitor::default();
+    config.parse_dictionary_visit("a = 1, b,".as_bytes(), &mut visitor)?;
+    assert_eq!(
+        vec!["key a", "item Integer(1)", "key b", "item Boolean(true)"],
+        visitor.events
+    );
+
+    let config = ParserConfig {
+        reject_duplicate_keys: true,
+        ..ParserConfig::default()
+    };
+    let mut visitor = RecordingVisitor::default();
+    assert_eq!(
+        Err("parse_dict: duplicate key"),
+        config.parse_dictionary_visit("a=1, a=2".as_bytes(), &mut visitor)
+    );
+    Ok(())
+}
+
+#[test]
+fn reusable_parser_matches_parser() -> Result<(), Box<dyn Error>> {
+    let inputs = [
+        "",
+        "\"foo\";a=tok, \"with \\\"escaped\\\" chars\"",
+        "a=(\"long string value\" token/value);key=:YWJjZGVm:, b",
+        "\"unterminated string",
+        ":YWJjZ:",
+        "1.50;b=?0, -12",
+        "a=1, a=2",
+        "\"s\"",
+    ];
+    // Buffer reused across all the inputs, including after errors
+    let mut parser = ReusableParser::new();
+    for input in inputs.iter() {
+        let input_bytes = input.as_bytes();
+        assert_eq!(
+            Parser::parse_item(input_bytes),
+            parser.parse_item(input_bytes),
+            "item: {}",
+            input
+        );
+        assert_eq!(
+            Parser::parse_list(input_bytes),
+            parser.parse_list(input_bytes),
+            "list: {}",
+            input
+        );
+        assert_eq!(
+            Parser::parse_dictionary(input_bytes),
+            parser.parse_dictionary(input_bytes),
+            "dictionary: {}",
+            input
+        );
+    }
+
+    let config = ParserConfig {
+        reject_duplicate_keys: true,
+        preserve_decimal_scale: true,
+        ..Parser::lenient()
+    };
+    let mut parser = ReusableParser::with_config(config.clone());
+    for input in inputs.iter() {
+        let input_bytes = input.as_bytes();
+        assert_eq!(
+            config.parse_item(input_bytes),
+            parser.parse_item(input_bytes)
+        );
+        assert_eq!(
+            config.parse_list(input_bytes),
+            parser.parse_list(input_bytes)
+        );
+        assert_eq!(
+            config.parse_dictionary(input_bytes),
+            parser.parse_dictionary(input_bytes)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_lenient_whitespace() -> Result<(), Box<dyn Error>> {
+    let lenient = ParserConfig {
+        lenient_whitespace: true,
+        ..ParserConfig::default()
+    };
+
+    let dict = lenient.parse_dictionary("a = 1 ; b = 2, c\t;\td , e=(1 2) ;f".as_bytes())?;
+    assert_eq!(
+        Parser::parse_dictionary("a=1;b=2, c;d, e=(1 2);f".as_bytes())?,
+        dict
+    );
+    assert_eq!("a=1;b=2, c;d, e=(1 2);f", dict.serialize_value()?);
+
+    let list = lenient.parse_list("tok ;q = 0.5, (1 ;a 2) ; b".as_bytes())?;
+    assert_eq!(Parser::parse_list("tok;q=0.5, (1;a 2);b".as_bytes())?, list);
+
+    let item = lenient.parse_item("\"foo\" ; a".as_bytes())?;
+    assert_eq!(Parser::parse_item("\"foo\";a".as_bytes())?, item);
+    Ok(())
+}
+
+#[test]
+fn parse_lenient_whitespace_errors() -> Result<(), Box<dyn Error>> {
+    let lenient = ParserConfig {
+        lenient_whitespace: true,
+        ..ParserConfig::default()
+    };
+
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary("a = 1 ; b = 2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_item("1 ;a".as_bytes())
+    );
+
+    // Whitespace is still required between inner list items and not allowed inside of them
+    assert_eq!(
+        Err("parse_inner_list: bad delimitation"),
+        lenient.parse_list("(1;a=2\"b\")".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        lenient.parse_item("1 2".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_lenient_config() -> Result<(), Box<dyn Error>> {
+    let input = "a = 1 , b = 2".as_bytes();
+    let dict = Parser::lenient().parse_dictionary(input)?;
+    assert_eq!(Parser::parse_dictionary("a=1, b=2".as_bytes())?, dict);
+    assert_eq!("a=1, b=2", dict.serialize_value()?);
+
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary(input)
+    );
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::strict().parse_dictionary(input)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_typed_item() -> Result<(), Box<dyn Error>> {
+    let item = Parser::parse_integer_item("-5;a".as_bytes())?;
+    assert_eq!(Parser::parse_item("-5;a".as_bytes())?, item);
+
+    let item = Parser::parse_string_item("\"foo\";a".as_bytes())?;
+    assert_eq!(Parser::parse_item("\"foo\";a".as_bytes())?, item);
+
+    let item = Parser::parse_token_item("*foo;a".as_bytes())?;
+    assert_eq!(Parser::parse_item("*foo;a".as_bytes())?, item);
+    Ok(())
+}
+
+#[test]
+fn parse_typed_item_errors() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("parse: expected integer, found decimal"),
+        Parser::parse_integer_item("1.5".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected integer, found byte-sequence"),
+        Parser::parse_integer_item(":YQ==:".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected string, found integer"),
+        Parser::parse_string_item("1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected string, found token"),
+        Parser::parse_string_item("foo".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected token, found string"),
+        Parser::parse_token_item("\"foo\"".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected token, found boolean"),
+        Parser::parse_token_item("?0".as_bytes())
+    );
+
+    // Parsing errors are reported as is
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_token_item("foo bar".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_scalar_item() -> Result<(), Box<dyn Error>> {
+    assert_eq!(120, Parser::parse_integer(" 120 ".as_bytes())?);
+    assert_eq!(-5, Parser::parse_integer("-5;a;b=2".as_bytes())?);
+    assert_eq!(
+        Decimal::from_str("-1.5")?,
+        Parser::parse_decimal("-1.50;a".as_bytes())?
+    );
+    assert!(!Parser::parse_boolean("?0;a".as_bytes())?);
+    assert_eq!(
+        "text/html",
+        Parser::parse_token_string("text/html;charset=utf-8".as_bytes())?
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_scalar_item_errors() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("parse: expected integer, found decimal"),
+        Parser::parse_integer("1.0".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected integer, found boolean"),
+        Parser::parse_integer("?1".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected decimal, found token"),
+        Parser::parse_decimal("a".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected boolean, found string"),
+        Parser::parse_boolean("\"?1\"".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: expected token, found byte-sequence"),
+        Parser::parse_token_string(":YQ==:".as_bytes())
+    );
+
+    // Parsing errors are reported as is
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_integer("1, 2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_number: integer too long, length > 15"),
+        Parser::parse_integer("-1000000000000000".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_from_reader() -> Result<(), Box<dyn Error>> {
+    let list = Parser::parse_list_reader(std::io::Cursor::new("1, (a b);x"))?;
+    assert_eq!(Parser::parse_list("1, (a b);x".as_bytes())?, list);
+
+    let dict = Parser::parse_dictionary_reader("a=1, b".as_bytes())?;
+    assert_eq!(Parser::parse_dictionary("a=1, b".as_bytes())?, dict);
+
+    let item = Parser::parse_item_reader(std::io::Cursor::new(b"?1;a=2".to_vec()))?;
+    assert_eq!(Parser::parse_item("?1;a=2".as_bytes())?, item);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_from_reader_errors() -> Result<(), Box<dyn Error>> {
+    struct FailingReader;
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+        }
+    }
+
+    assert_eq!(
+        Err("parse: failed to read input"),
+        Parser::parse_list_reader(FailingReader)
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_item_reader("1 2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Parser::parse_dictionary_reader("a=\"ü\"".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_byte_sequence_max_len() -> Result<(), Box<dyn Error>> {
+    let config = ParserConfig {
+        max_byte_seq_len: Some(10),
+        ..ParserConfig::default()
+    };
+
+    // 16 bytes
+    assert_eq!(
+        Err("parse_byte_seq: byte sequence is too long"),
+        config.parse_item(":MDEyMzQ1Njc4OWFiY2RlZg==:".as_bytes())
+    );
+    // 8 bytes
+    assert_eq!(
+        BareItem::ByteSeq(b"01234567".to_vec()),
+        config.parse_item(":MDEyMzQ1Njc=:".as_bytes())?.bare_item
+    );
+    // Exactly 10 bytes, with and without padding
+    assert_eq!(
+        BareItem::ByteSeq(b"0123456789".to_vec()),
+        config
+            .parse_item(":MDEyMzQ1Njc4OQ==:".as_bytes())?
+            .bare_item
+    );
+    assert_eq!(
+        BareItem::ByteSeq(b"0123456789".to_vec()),
+        config.parse_item(":MDEyMzQ1Njc4OQ:".as_bytes())?.bare_item
+    );
+    // 11 bytes
+    assert_eq!(
+        Err("parse_byte_seq: byte sequence is too long"),
+        config.parse_list("a, :MDEyMzQ1Njc4OWE=:".as_bytes())
+    );
+    assert!(Parser::parse_item(":MDEyMzQ1Njc4OWFiY2RlZg==:".as_bytes()).is_ok());
+    Ok(())
+}
+
+#[test]
+fn parse_list_spanned() -> Result<(), Box<dyn Error>> {
+    let input = "a, (1 2), b";
+    let members = Parser::parse_list_spanned(input.as_bytes())?;
+    let spans: Vec<_> = members.iter().map(|(_, span)| span.clone()).collect();
+    assert_eq!(vec![0..1, 3..8, 10..11], spans);
+    let entries: List = members.into_iter().map(|(member, _)| member).collect();
+    assert_eq!(Parser::parse_list(input.as_bytes())?, entries);
+
+    let input = "  tok;a=1 ,\t(\"x\" :YQ==:);b  ";
+    let members = Parser::parse_list_spanned(input.as_bytes())?;
+    let spans: Vec<&str> = members
+        .iter()
+        .map(|(_, span)| &input[span.clone()])
+        .collect();
+    assert_eq!(vec!["tok;a=1", "(\"x\" :YQ==:);b"], spans);
+
+    assert!(Parser::parse_list_spanned("".as_bytes())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn parse_list_spanned_errors() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("parse_list: trailing comma"),
+        Parser::parse_list_spanned("a, b,".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_list: trailing characters after list member"),
+        Parser::parse_list_spanned("a b".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Parser::parse_list_spanned("\"\u{e9}\"".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_allow_trailing_comma() -> Result<(), Box<dyn Error>> {
+    let config = ParserConfig {
+        allow_trailing_comma: true,
+        ..ParserConfig::default()
+    };
+
+    let dict = config.parse_dictionary("a=1, b=2,".as_bytes())?;
+    assert_eq!(Parser::parse_dictionary("a=1, b=2".as_bytes())?, dict);
+    let dict = config.parse_dictionary("a=1;x , ".as_bytes())?;
+    assert_eq!("a=1;x", dict.serialize_value()?);
+
+    let list = config.parse_list("1, (2 3),".as_bytes())?;
+    assert_eq!(Parser::parse_list("1, (2 3)".as_bytes())?, list);
+    let list = config.parse_list("tok,\t".as_bytes())?;
+    assert_eq!("tok", list.serialize_value()?);
+    Ok(())
+}
+
+#[test]
+fn parse_allow_trailing_comma_errors() -> Result<(), Box<dyn Error>> {
+    let config = ParserConfig {
+        allow_trailing_comma: true,
+        ..ParserConfig::default()
+    };
+
+    assert_eq!(
+        Err("parse_dict: trailing comma"),
+        Parser::parse_dictionary("a=1, b=2,".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_list: trailing comma"),
+        Parser::parse_list("1, 2,".as_bytes())
+    );
+
+    // Empty members are rejected
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        config.parse_dictionary("a=1,,b=2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        config.parse_dictionary("a=1,,".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        config.parse_list("1, ,2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_bare_item: item type can't be identified"),
+        config.parse_list(",".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_item_checked() -> Result<(), Box<dyn Error>> {
+    let canonical_inputs = [
+        "1",
+        "-1.5;q=0.25",
+        "\"a \\\"b\\\"\";x",
+        ":aGVsbG8=:;y=?0",
+        "*tok/en;a;b=c",
+        "  12;a  ",
+        "1.0",
+    ];
+    for input in canonical_inputs.iter() {
+        let (item, canonicality) = Parser::parse_item_checked(input.as_bytes())?;
+        assert_eq!(Canonicality::Canonical, canonicality, "{}", input);
+        assert_eq!(Parser::parse_item(input.as_bytes())?, item);
+    }
+
+    let non_canonical_inputs = ["1.50", "1.00", "01", "-0", "1;a=?1", "1; a", ":aGVsbG8:"];
+    for input in non_canonical_inputs.iter() {
+        let (item, canonicality) = Parser::parse_item_checked(input.as_bytes())
+            .map_err(|_| format!("{} failed to parse", input))?;
+        assert_eq!(Canonicality::NonCanonical, canonicality, "{}", input);
+        assert_eq!(Parser::parse_item(input.as_bytes())?, item);
+    }
+
+    assert_eq!(
+        Err("parse_bare_item: empty item"),
+        Parser::parse_item_checked("  ".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_auto() -> Result<(), Box<dyn Error>> {
+    let items = ["tok", "1;a=2", "\"str\"", "  ?0  "];
+    for input in items.iter() {
+        assert_eq!(
+            FieldValue::Item(Parser::parse_item(input.as_bytes())?),
+            Parser::parse_auto(input.as_bytes())?
+        );
+    }
+
+    // Inner lists can't be Items
+    let lists = ["a, b", "(1 2)", "(1 2), 3", "1;a, 2;b"];
+    for input in lists.iter() {
+        assert_eq!(
+            FieldValue::List(Parser::parse_list(input.as_bytes())?),
+            Parser::parse_auto(input.as_bytes())?
+        );
+    }
+    // Empty input is not an Item, but is an empty List
+    assert_eq!(FieldValue::List(vec![]), Parser::parse_auto("".as_bytes())?);
+    assert_eq!(
+        FieldValue::List(vec![]),
+        Parser::parse_auto("  ".as_bytes())?
+    );
+
+    let dicts = ["a=1", "a, b=(1 2)", "a=tok;x, b;y=2", "a=?1"];
+    for input in dicts.iter() {
+        assert_eq!(
+            FieldValue::Dictionary(Parser::parse_dictionary(input.as_bytes())?),
+            Parser::parse_auto(input.as_bytes())?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_auto_errors() -> Result<(), Box<dyn Error>> {
+    let invalid_inputs = ["a=", "1=2", "a, ", "a b", "(1"];
+    for input in invalid_inputs.iter() {
+        assert_eq!(
+            Parser::parse_dictionary(input.as_bytes()).map(FieldValue::Dictionary),
+            Parser::parse_auto(input.as_bytes())
+        );
+        assert!(Parser::parse_auto(input.as_bytes()).is_err());
+    }
+    assert_eq!(
+        Err("parse_key: first character is not lcalpha or '*'"),
+        Parser::parse_auto("1=2".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_byte_seq_into_reused_buffer() -> Result<(), Box<dyn Error>> {
+    let mut buffer = Vec::new();
+
+    Parser::parse_byte_seq_into(" :MDEyMzQ1Njc4OWFiY2RlZg==: ".as_bytes(), &mut buffer)?;
+    assert_eq!(b"0123456789abcdef".to_vec(), buffer);
+    let capacity = buffer.capacity();
+
+    Parser::parse_byte_seq_into(":aGVsbG8:".as_bytes(), &mut buffer)?;
+    assert_eq!(b"hello".to_vec(), buffer);
+    Parser::parse_byte_seq_into(":aGk=:".as_bytes(), &mut buffer)?;
+    assert_eq!(b"hi".to_vec(), buffer);
+    Parser::parse_byte_seq_into("::".as_bytes(), &mut buffer)?;
+    assert!(buffer.is_empty());
+    assert_eq!(capacity, buffer.capacity());
+
+    buffer.extend_from_slice(b"stale");
+    assert_eq!(
+        Err("parse_byte_seq: decoding error"),
+        Parser::parse_byte_seq_into(":aGVsbG8ab:".as_bytes(), &mut buffer)
+    );
+    assert!(buffer.is_empty());
+    assert_eq!(
+        Err("parse_byte_seq: first char is not ':'"),
+        Parser::parse_byte_seq_into("aGVsbG8=".as_bytes(), &mut buffer)
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_byte_seq_into(":aGVsbG8=:;a=1".as_bytes(), &mut buffer)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_bare_string() -> Result<(), Box<dyn Error>> {
+    let input = r#" "hello; world=(1 2)" "#;
+    let value = Parser::parse_bare_string(input.as_bytes())?;
+    assert!(matches!(value, Cow::Borrowed("hello; world=(1 2)")));
+    assert!(matches!(
+        Parser::parse_bare_string("\"\"".as_bytes())?,
+        Cow::Borrowed("")
+    ));
+
+    let value = Parser::parse_bare_string(r#""a \"b\" \\ c""#.as_bytes())?;
+    assert!(matches!(value, Cow::Owned(_)));
+    assert_eq!(r#"a "b" \ c"#, value);
+
+    // Results match parsing of the String bare item
+    for input in [r#""plain""#, r#""esc\\aped""#, r#""""#].iter() {
+        assert_eq!(
+            Parser::parse_bare_item(input.as_bytes())?.as_str(),
+            Some(Parser::parse_bare_string(input.as_bytes())?.as_ref())
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_bare_string_errors() {
+    assert_eq!(
+        Err("parse_string: first character is not '\"'"),
+        Parser::parse_bare_string("tok".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_string: no closing '\"'"),
+        Parser::parse_bare_string("\"abc".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_string("\"a\"b\"".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: trailing characters after parsed value"),
+        Parser::parse_bare_string("\"a\";x".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_string: not a visible character"),
+        Parser::parse_bare_string("\"a\tb\"".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_string: disallowed character after '\\'"),
+        Parser::parse_bare_string("\"a\\nb\"".as_bytes())
+    );
+    assert_eq!(
+        Err("parse: non-ascii characters in input"),
+        Parser::parse_bare_string("\"é\"".as_bytes())
+    );
+}
+
+#[test]
+fn parse_dict_duplicate_keys() -> Result<(), Box<dyn Error>> {
+    // Last member wins by default
+    let dict = Parser::parse_dictionary("a=1, b, a=2;x".as_bytes())?;
+    assert_eq!(2, dict.len());
+    assert_eq!("a=2;x, b", dict.serialize_value()?);
+
+    let config = ParserConfig {
+        reject_duplicate_keys: true,
+        ..ParserConfig::default()
+    };
+    assert_eq!(
+        Err("parse_dict: duplicate key"),
+        config.parse_dictionary("a=1, a=2".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_dict: duplicate key"),
+        config.parse_dictionary("a, b=(1 2), c=?0, b".as_bytes())
+    );
+    assert_eq!(
+        Parser::parse_dictionary("a=1, b;a=2".as_bytes())?,
+        config.parse_dictionary("a=1, b;a=2".as_bytes())?
+    );
+
+    // Duplicate parameter keys are not affected
+    let dict = config.parse_dictionary("a;x=1;x=2".as_bytes())?;
+    assert_eq!("a;x=2", dict.serialize_value()?);
+    Ok(())
+}
+
+#[test]
+fn parse_dict_multi() -> Result<(), Box<dyn Error>> {
+    let members = Parser::parse_dictionary_multi("a=1, b;x, a=(1 2), c, b=?0".as_bytes())?;
+    let expected: Vec<(String, ListEntry)> = vec![
+        ("a".to_owned(), Item::new(1.into()).into()),
+        (
+            "b".to_owned(),
+            Parser::parse_item("?1;x".as_bytes())?.into(),
+        ),
+        (
+            "a".to_owned(),
+            InnerList::new(vec![Item::new(1.into()), Item::new(2.into())]).into(),
+        ),
+        ("c".to_owned(), Item::new(BareItem::Boolean(true)).into()),
+        ("b".to_owned(), Item::new(BareItem::Boolean(false)).into()),
+    ];
+    assert_eq!(expected, members);
+
+    // Collapsing members gives the same result as parsing into Dictionary
+    let dict: Dictionary = members.into_iter().collect();
+    assert_eq!(
+        Parser::parse_dictionary("a=1, b;x, a=(1 2), c, b=?0".as_bytes())?,
+        dict
+    );
+
+    assert!(Parser::parse_dictionary_multi(" ".as_bytes())?.is_empty());
+    assert_eq!(
+        Err("parse_dict: trailing comma"),
+        Parser::parse_dictionary_multi("a=1, a=2,".as_bytes())
+    );
+    assert_eq!(
+        Err("parse_dict: trailing characters after dictionary member"),
+        Parser::parse_dictionary_multi("a=1 ;".as_bytes())
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_list_lossy() -> Result<(), Box<dyn Error>> {
+    let (list, errors) = Parser::parse_list_lossy("1;a, 2 3, (4 5);b".as_bytes());
+    assert_eq!(Parser::parse_list("1;a, (4 5);b".as_bytes())?, list);
+    assert_eq!(
+        vec!["parse_list: trailing characters after list member"],
+        errors
+    );
+
+    // Commas inside of strings and inner lists don't end skipped members
+    let input = r#"a, "x, y" z, (b "c, d" ?2), e, @bad, f"#;
+    let (list, errors) = Parser::parse_list_lossy(input.as_bytes());
+    assert_eq!(Parser::parse_list("a, e, f".as_bytes())?, list);
+    assert_eq!(
+        vec![
+            "parse_list: trailing characters after list member",
+            "parse_bool: invalid variant",
+            "parse_bare_item: item type can't be identified",
+        ],
+        errors
+    );
+
+    // Escaped quote doesn't end a skipped string
+    let (list, errors) = Parser::parse_list_lossy(r#"a "b\", c", d"#.as_bytes());
+    assert_eq!(Parser::parse_list("d".as_bytes())?, list);
+    assert_eq!(1, errors.len());
+
+    // Valid input is parsed without errors, the same as by parse_list
+    let input = "  a;x=1,\t(b c), \"d\"  ";
+    assert_eq!(
+        (Parser::parse_list(input.as_bytes())?, vec![]),
+        Parser::parse_list_lossy(input.as_bytes())
+    );
+    assert_eq!((vec![], vec![]), Parser::parse_list_lossy("".as_bytes()));
+    Ok(())
+}
+
+#[test]
+fn parse_list_lossy_errors() -> Result<(), Box<dyn Error>> {
+    let (list, errors) = Parser::parse_list_lossy("a, , b,".as_bytes());
+    assert_eq!(Parser::parse_list("a, b".as_bytes())?, list);
+    assert_eq!(
+        vec![
+            "parse_bare_item: item type can't be identified",
+            "parse_list: trailing comma",
+        ],
+        errors
+    );
+
+    let (list, errors) = Parser::parse_list_lossy("a, (b".as_bytes());
+    assert_eq!(Parser::parse_list("a".as_bytes())?, list);
+    assert_eq!(
+        vec!["parse_inner_list: the end of the inner list was not found"],
+        errors
+    );
+
+    assert_eq!(
+        (vec![], vec!["parse: non-ascii characters in input"]),
+        Parser::parse_list_lossy("a, \u{e9}".as_bytes())
+    );
+    Ok(())
+}
@@ -1,13 +1,16 @@
 use crate::serializer::Serializer;
 use crate::FromStr;
-use crate::SerializeValue;
-use crate::{BareItem, Decimal, Dictionary, InnerList, Item, List, Parameters};
+use crate::{
+    append_list_member, classify_str, locate_invalid_chars, quote_string, serialize_list_from_iter,
+    InvalidChars, Parser, SerializeOptions, SerializePretty, SerializeValue, StrClassification,
+};
+use crate::{BareItem, Decimal, Dictionary, InnerList, Item, List, ListEntry, Parameters};
 use std::error::Error;
 use std::iter::FromIterator;
 
 #[test]
 fn serialize_value_empty_dict() -> Result<(), Box<dyn Error>> {
-    let dict_field_value = Dictionary::new();
+    let dict_field_value = Dictionary::default();
     assert_eq!(
         Err("serialize_dictionary: serializing empty field is not allowed"),
         dict_field_value.serialize_value()
@@ -25,6 +28,109 @@ fn serialize_value_empty_list() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn serialize_value_or_empty() -> Result<(), Box<dyn Error>> {
+    assert_eq!(None, Dictionary::default().serialize_value_or_empty()?);
+    assert_eq!(None, List::new().serialize_value_or_empty()?);
+
+    let list: List = vec![Item::new(1.into()).into()];
+    assert_eq!(Some("1".to_owned()), list.serialize_value_or_empty()?);
+
+    let dict = Dictionary::from_iter(vec![("a".to_owned(), Item::new(1.into()).into())]);
+    assert_eq!(Some("a=1".to_owned()), dict.serialize_value_or_empty()?);
+
+    let item = Item::new(BareItem::Token("tok".to_owned()));
+    assert_eq!(Some("tok".to_owned()), item.serialize_value_or_empty()?);
+    Ok(())
+}
+
+#[test]
+fn serialized_len() -> Result<(), Box<dyn Error>> {
+    let items = vec![
+        "1",
+        "-999999999999999",
+        "0",
+        "-10.5;a",
+        "13.0",
+        "\"say \\\"hi\\\"\\\\\";x=\"\\\\\"",
+        "\"\"",
+        ":aGVsbG8=:;b=:YQ==:",
+        "*tok/en:1;c=?0",
+        "?1;d",
+    ];
+    for input in items.iter() {
+        let item = Parser::parse_item(input.as_bytes())?;
+        assert_eq!(item.serialize_value()?.len(), item.serialized_len()?);
+    }
+
+    // Decimals which are rounded or normalized when serialized
+    let decimals = [
+        "1.2345",
+        "-0.0004",
+        "0.0005",
+        "12.500",
+        "-7.0",
+        "999999999999.999",
+        "0.010",
+    ];
+    for input in decimals.iter() {
+        let item = Item::new(BareItem::Decimal(Decimal::from_str(input)?));
+        assert_eq!(item.serialize_value()?.len(), item.serialized_len()?);
+    }
+
+    let input = "(\"a\\\\b\" tok);p=1, (), (1);q, :Zm9vYg==:;r=-4.125";
+    let list = Parser::parse_list(input.as_bytes())?;
+    assert_eq!(list.serialize_value()?.len(), list.serialized_len()?);
+
+    let input = "a=1, b, c;x=?0, d=(1 2);y, e=?0, f=\"\\\"\"";
+    let dict = Parser::parse_dictionary(input.as_bytes())?;
+    assert_eq!(dict.serialize_value()?.len(), dict.serialized_len()?);
+    Ok(())
+}
+
+#[test]
+fn serialized_len_errors() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("serialize_list: serializing empty field is not allowed"),
+        List::new().serialized_len()
+    );
+    assert_eq!(
+        Err("serialize_dictionary: serializing empty field is not allowed"),
+        Dictionary::default().serialized_len()
+    );
+
+    let item = Item::new(BareItem::Integer(1_000_000_000_000_000));
+    assert_eq!(
+        Err("serialize_integer: integer is out of range"),
+        item.serialized_len()
+    );
+
+    let item = Item::new(BareItem::String("non-ascii \u{1f439}".to_owned()));
+    assert_eq!(
+        Err("serialize_string: non-ascii character"),
+        item.serialized_len()
+    );
+
+    let mut params = Parameters::default();
+    params.insert("Key".to_owned(), 1.into());
+    let item = Item::with_params(BareItem::Boolean(true), params);
+    assert_eq!(
+        Err("serialize_key: disallowed character in input"),
+        item.serialized_len()
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_value_or_empty_errors() -> Result<(), Box<dyn Error>> {
+    let list: List = vec![Item::new(BareItem::Token("7tok".to_owned())).into()];
+    assert_eq!(
+        Err("serialise_token: first character is not ALPHA or '*'"),
+        list.serialize_value_or_empty()
+    );
+    Ok(())
+}
+
 #[test]
 fn serialize_value_list_mixed_members_with_params() -> Result<(), Box<dyn Error>> {
     let item1 = Item::new(Decimal::from_str("42.4568")?.into());
@@ -177,7 +283,7 @@ fn serialize_decimal() -> Result<(), Box<dyn Error>> {
 
     buf.clear();
     Serializer::serialize_decimal(Decimal::from_str("-100.130")?, &mut buf)?;
-    assert_eq!("-100.130", &buf);
+    assert_eq!("-100.13", &buf);
 
     buf.clear();
     Serializer::serialize_decimal(Decimal::from_str("-137.0")?, &mut buf)?;
@@ -193,6 +299,34 @@ fn serialize_decimal() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn serialize_decimal_fraction_digits() -> Result<(), Box<dyn Error>> {
+    // Output always has a decimal point followed by one to three fractional digits
+    let cases = vec![
+        ("0.5", "0.5"),
+        ("1.0", "1.0"),
+        ("1", "1.0"),
+        ("-0.1", "-0.1"),
+        ("-0.0", "0.0"),
+        // Trailing zeros are removed, whatever the scale of the value
+        ("1.50", "1.5"),
+        ("2.500", "2.5"),
+        ("-3.10", "-3.1"),
+        // Rounding to three fractional digits is half to even
+        ("1.2345", "1.234"),
+        ("1.2355", "1.236"),
+        ("0.9995", "1.0"),
+        ("-0.9996", "-1.0"),
+        ("-0.0004", "0.0"),
+    ];
+    for (input, expected) in cases {
+        let mut buf = String::new();
+        Serializer::serialize_decimal(Decimal::from_str(input)?, &mut buf)?;
+        assert_eq!(expected, &buf, "input: {}", input);
+    }
+    Ok(())
+}
+
 #[test]
 fn serialize_decimal_errors() -> Result<(), Box<dyn Error>> {
     let mut buf = String::new();
@@ -231,6 +365,28 @@ fn serialize_string() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn serialize_string_escaping_round_trip() -> Result<(), Box<dyn Error>> {
+    let cases = [
+        ("a\\", "\"a\\\\\""),
+        ("\\\\", "\"\\\\\\\\\""),
+        ("\"quoted\"", "\"\\\"quoted\\\"\""),
+        ("\\\"", "\"\\\\\\\"\""),
+        ("\\", "\"\\\\\""),
+        ("\"", "\"\\\"\""),
+    ];
+    for (value, expected) in cases.iter() {
+        let mut buf = String::new();
+        Serializer::serialize_string(value, &mut buf)?;
+        assert_eq!(expected, &buf);
+        assert_eq!(
+            BareItem::String(value.to_string()),
+            Parser::parse_bare_item(buf.as_bytes())?
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn serialize_string_errors() -> Result<(), Box<dyn Error>> {
     let mut buf = String::new();
@@ -291,12 +447,57 @@ fn serialize_token_errors() -> Result<(), Box<dyn Error>> {
         Err("serialise_token: disallowed character"),
         Serializer::serialize_token("abc:de\t", &mut buf)
     );
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        Serializer::serialize_token("", &mut buf)
+    );
+    assert!(buf.is_empty());
+    Ok(())
+}
+
+#[test]
+fn serialize_value_rejects_invalid_tokens_and_keys() -> Result<(), Box<dyn Error>> {
+    // Values constructed directly are validated only when serialized
+    let empty_token = Item::new(BareItem::Token("".to_owned()));
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        empty_token.serialize_value()
+    );
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        empty_token.serialized_len()
+    );
+
+    let list: List =
+        vec![InnerList::new(vec![Item::new(BareItem::Token("a b".to_owned()))]).into()];
+    assert_eq!(
+        Err("serialise_token: disallowed character"),
+        list.serialize_value()
+    );
+
+    let dict = Dictionary::from_iter(vec![("".to_owned(), Item::new(1.into()).into())]);
+    assert_eq!(Err("serialize_key: empty key"), dict.serialize_value());
+
+    let item = Item::with_params(
+        1.into(),
+        Parameters::from_iter(vec![("".to_owned(), BareItem::Boolean(true))]),
+    );
+    assert_eq!(Err("serialize_key: empty key"), item.serialize_value());
     Ok(())
 }
 
 #[test]
 fn serialize_byte_sequence() -> Result<(), Box<dyn Error>> {
     let mut buf = String::new();
+    // Input longer than a single encoding chunk, with each possible remainder length
+    for len in 94..=99 {
+        let value: Vec<u8> = (0..len).map(|byte| byte as u8).collect();
+        buf.clear();
+        Serializer::serialize_byte_sequence(&value, &mut buf)?;
+        assert_eq!(format!(":{}:", data_encoding::BASE64.encode(&value)), buf);
+    }
+
+    buf.clear();
     Serializer::serialize_byte_sequence("hello".as_bytes(), &mut buf)?;
     assert_eq!(":aGVsbG8=:", &buf);
 
@@ -433,6 +634,11 @@ fn serialize_key_erros() -> Result<(), Box<dyn Error>> {
         Err("serialize_key: first character is not lcalpha or '*'"),
         Serializer::serialize_key("7key", &mut buf)
     );
+    assert_eq!(
+        Err("serialize_key: empty key"),
+        Serializer::serialize_key("", &mut buf)
+    );
+    assert!(buf.is_empty());
     Ok(())
 }
 
@@ -456,6 +662,69 @@ fn serialize_list_of_items_and_inner_list() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn serialize_list_from_filtered_iter() -> Result<(), Box<dyn Error>> {
+    let mut buf = String::new();
+
+    let input = Parser::parse_list("1, (a b);x, 2;y, tok, (c)".as_bytes())?;
+    let members = input.into_iter().filter(|member| match member {
+        ListEntry::Item(item) => item.bare_item.as_int().is_some(),
+        ListEntry::InnerList(_) => true,
+    });
+
+    serialize_list_from_iter(members, &mut buf)?;
+    assert_eq!("1, (a b);x, 2;y, (c)", &buf);
+    Ok(())
+}
+
+#[test]
+fn serialize_list_from_iter_errors() -> Result<(), Box<dyn Error>> {
+    let mut buf = String::new();
+
+    let members = (0..3).map(|i| ListEntry::from(Item::new(i.into())));
+    assert_eq!(
+        Err("serialize_list: serializing empty field is not allowed"),
+        serialize_list_from_iter(members.filter(|_| false), &mut buf)
+    );
+    assert!(buf.is_empty());
+
+    let members = vec![ListEntry::from(Item::new(BareItem::Token("".to_owned())))];
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        serialize_list_from_iter(members, &mut buf)
+    );
+    Ok(())
+}
+
+#[test]
+fn append_list_members() -> Result<(), Box<dyn Error>> {
+    let list = Parser::parse_list("1;a, (b c);d=2, \"str\", :aGVsbG8=:".as_bytes())?;
+
+    let mut buf = String::new();
+    for member in &list {
+        append_list_member(&mut buf, member)?;
+    }
+    assert_eq!(list.serialize_value()?, buf);
+
+    // Appending to a field serialized elsewhere
+    let mut buf = list.serialize_value()?;
+    append_list_member(&mut buf, &Item::new(BareItem::Boolean(false)).into())?;
+    assert_eq!("1;a, (b c);d=2, \"str\", :aGVsbG8=:, ?0", buf);
+    Ok(())
+}
+
+#[test]
+fn append_list_member_errors() {
+    let mut buf = String::new();
+    assert_eq!(
+        Err("serialize_string: non-ascii character"),
+        append_list_member(
+            &mut buf,
+            &Item::new(BareItem::String("\u{e9}".to_owned())).into()
+        )
+    );
+}
+
 #[test]
 fn serialize_list_of_lists() -> Result<(), Box<dyn Error>> {
     let mut buf = String::new();
@@ -498,7 +767,7 @@ fn serialize_dictionary_with_params() -> Result<(), Box<dyn Error>> {
         ("a".to_owned(), 1.into()),
         ("b".to_owned(), BareItem::Boolean(true)),
     ]);
-    let item2_params = Parameters::new();
+    let item2_params = Parameters::default();
     let item3_params = Parameters::from_iter(vec![
         ("q".to_owned(), BareItem::Boolean(false)),
         ("r".to_owned(), BareItem::String("+w".to_owned())),
@@ -529,3 +798,301 @@ fn serialize_dict_empty_member_value() -> Result<(), Box<dyn Error>> {
     assert_eq!("a=()", &buf);
     Ok(())
 }
+
+#[test]
+fn serialize_pretty() -> Result<(), Box<dyn Error>> {
+    let list = Parser::parse_list("tok;a=1;b, (1 \"str\";c=?0);d=:aGVsbG8=:, ()".as_bytes())?;
+    let expected = "tok\n  ;a=1\n  ;b\n(\n  1\n  \"str\"\n    ;c=?0\n)\n  ;d=:aGVsbG8=:\n(\n)";
+    assert_eq!(expected, list.serialize_pretty());
+
+    let dict = Parser::parse_dictionary("a=1.5, b;c=2, d=(x y)".as_bytes())?;
+    let expected = "a=1.5\nb\n  ;c=2\nd=(\n  x\n  y\n)";
+    assert_eq!(expected, dict.serialize_pretty());
+
+    let item = Item::with_params(
+        BareItem::Token("7invalid".to_owned()),
+        Parameters::from_iter(vec![("a".to_owned(), 1.into())]),
+    );
+    assert_eq!("Token(\"7invalid\")\n  ;a=1", item.serialize_pretty());
+
+    assert_eq!("", List::new().serialize_pretty());
+    Ok(())
+}
+
+#[test]
+fn serialize_quote_string() -> Result<(), Box<dyn Error>> {
+    assert_eq!("\"\"", quote_string("")?);
+    assert_eq!("\"foo bar\"", quote_string("foo bar")?);
+    assert_eq!("\"a\\\\b\\\"c\"", quote_string("a\\b\"c")?);
+
+    let quoted = quote_string("report \"to\"")?;
+    assert_eq!(
+        BareItem::String("report \"to\"".to_owned()),
+        Parser::parse_bare_item(quoted.as_bytes())?
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_quote_string_errors() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        Err("serialize_string: non-ascii character"),
+        quote_string("caf\u{e9}")
+    );
+    assert_eq!(
+        Err("serialize_string: not a visible character"),
+        quote_string("tab\there")
+    );
+    Ok(())
+}
+
+#[test]
+fn display_list_members() -> Result<(), Box<dyn Error>> {
+    let list = Parser::parse_list("(1 2);x, ();y=?0, tok;a=\"str\"".as_bytes())?;
+    assert_eq!("(1 2);x", list[0].to_string());
+    assert_eq!("();y=?0", list[1].to_string());
+    assert_eq!("tok;a=\"str\"", list[2].to_string());
+
+    assert_eq!("()", InnerList::new(vec![]).to_string());
+    let inner_list = list[0].as_inner_list().ok_or("not an inner list")?;
+    assert_eq!("(1 2);x", inner_list.to_string());
+    assert_eq!("[tok;a=\"str\"]", format!("[{}]", list[2]));
+    Ok(())
+}
+
+#[test]
+fn display_invalid_list_members() {
+    // Values which can't be serialized are displayed on a best-effort basis rather than failing
+    let item = Item::new(BareItem::Token("1abc".to_owned()));
+    assert!(item.serialize_value().is_err());
+    assert_eq!("Token(\"1abc\")", item.to_string());
+    assert_eq!(
+        "Token(\"\")",
+        Item::new(BareItem::Token("".to_owned())).to_string()
+    );
+
+    let mut item = Item::new(1.into());
+    item.params
+        .insert("Key".to_owned(), BareItem::String("tab\t".to_owned()));
+    item.params.insert("a".to_owned(), 2.into());
+    assert_eq!("1;Key=String(\"tab\\t\");a=2", item.to_string());
+
+    let inner_list = InnerList::new(vec![
+        Item::new(BareItem::Token("".to_owned())),
+        Item::new(2.into()),
+    ]);
+    let list: List = vec![inner_list.clone().into()];
+    assert!(list.serialize_value().is_err());
+    assert_eq!("(Token(\"\") 2)", inner_list.to_string());
+    assert_eq!(
+        "[(Token(\"\") 2)]",
+        format!("[{}]", ListEntry::InnerList(inner_list))
+    );
+}
+
+#[test]
+fn classify_strings() {
+    let classify = |value| {
+        let StrClassification {
+            is_token,
+            is_key,
+            is_string,
+        } = classify_str(value);
+        (is_token, is_key, is_string)
+    };
+
+    assert_eq!((true, true, true), classify("gzip"));
+    assert_eq!((true, true, true), classify("*"));
+    assert_eq!((true, false, true), classify("Gzip"));
+    assert_eq!((true, false, true), classify("text/html"));
+    // Every key is also a token
+    assert_eq!((true, true, true), classify("max-age_2"));
+    assert_eq!((true, true, true), classify("a.b"));
+    assert_eq!((false, false, true), classify("1abc"));
+    assert_eq!((false, false, true), classify("hello world"));
+    assert_eq!((false, false, true), classify("say \"hi\""));
+    assert_eq!((false, false, true), classify(""));
+    assert_eq!((false, false, false), classify("tab\there"));
+    assert_eq!((false, false, false), classify("caf\u{e9}"));
+}
+
+#[test]
+fn locate_invalid_chars_in_strings() {
+    assert_eq!(
+        InvalidChars {
+            token: None,
+            key: None,
+            string: None,
+        },
+        locate_invalid_chars("gzip")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: None,
+            key: Some((0, 'G')),
+            string: None,
+        },
+        locate_invalid_chars("Gzip")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: Some((0, '1')),
+            key: Some((0, '1')),
+            string: None,
+        },
+        locate_invalid_chars("1abc")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: Some((5, ' ')),
+            key: Some((5, ' ')),
+            string: None,
+        },
+        locate_invalid_chars("hello world")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: Some((3, '\t')),
+            key: Some((3, '\t')),
+            string: Some((3, '\t')),
+        },
+        locate_invalid_chars("tab\there")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: Some((3, '\u{e9}')),
+            key: Some((3, '\u{e9}')),
+            string: Some((3, '\u{e9}')),
+        },
+        locate_invalid_chars("caf\u{e9}\u{e9}")
+    );
+    assert_eq!(
+        InvalidChars {
+            token: None,
+            key: None,
+            string: None,
+        },
+        locate_invalid_chars("")
+    );
+}
+
+#[test]
+fn serialize_value_with_options() -> Result<(), Box<dyn Error>> {
+    let input = "a=1;x;y=2, b;z=\"s; t=u\", c=(1;p 2);q=?0";
+    let dict = Parser::parse_dictionary(input.as_bytes())?;
+
+    assert_eq!(
+        input,
+        dict.serialize_value_with(&SerializeOptions::default())?
+    );
+
+    let options = SerializeOptions {
+        space_after_semicolon: true,
+        ..SerializeOptions::default()
+    };
+    let spaced = dict.serialize_value_with(&options)?;
+    assert_eq!("a=1; x; y=2, b; z=\"s; t=u\", c=(1; p 2); q=?0", spaced);
+    assert_eq!(dict, Parser::parse_dictionary(spaced.as_bytes())?);
+
+    let options = SerializeOptions {
+        space_after_equals: true,
+        ..SerializeOptions::default()
+    };
+    let spaced = dict.serialize_value_with(&options)?;
+    assert_eq!("a= 1;x;y=2, b;z=\"s; t=u\", c= (1;p 2);q=?0", spaced);
+    assert_eq!(dict, Parser::lenient().parse_dictionary(spaced.as_bytes())?);
+
+    let options = SerializeOptions {
+        space_after_semicolon: true,
+        space_after_equals: true,
+        ..SerializeOptions::default()
+    };
+    assert_eq!(
+        "a= 1; x; y=2, b; z=\"s; t=u\", c= (1; p 2); q=?0",
+        dict.serialize_value_with(&options)?
+    );
+
+    let list = Parser::parse_list("tok;a=1, (x;b);c".as_bytes())?;
+    assert_eq!("tok; a=1, (x; b); c", list.serialize_value_with(&options)?);
+    let item = Parser::parse_item("1;a;b=2".as_bytes())?;
+    assert_eq!("1; a; b=2", item.serialize_value_with(&options)?);
+    Ok(())
+}
+
+#[test]
+fn serialize_value_with_member_separator() -> Result<(), Box<dyn Error>> {
+    let options = SerializeOptions {
+        member_separator: ",",
+        ..SerializeOptions::default()
+    };
+
+    let list = Parser::parse_list("a;x=1, (b c);y, 2".as_bytes())?;
+    let compact = list.serialize_value_with(&options)?;
+    assert_eq!("a;x=1,(b c);y,2", compact);
+    assert_eq!(list, Parser::parse_list(compact.as_bytes())?);
+
+    let dict = Parser::parse_dictionary("a=1, b, c=(x y)".as_bytes())?;
+    let compact = dict.serialize_value_with(&options)?;
+    assert_eq!("a=1,b,c=(x y)", compact);
+    assert_eq!(dict, Parser::parse_dictionary(compact.as_bytes())?);
+
+    // Separator is emitted verbatim, even if parsers won't accept it
+    let options = SerializeOptions {
+        member_separator: " | ",
+        ..SerializeOptions::default()
+    };
+    let piped = list.serialize_value_with(&options)?;
+    assert_eq!("a;x=1 | (b c);y | 2", piped);
+    assert!(Parser::parse_list(piped.as_bytes()).is_err());
+
+    // Single member has no separator
+    let list = Parser::parse_list("a".as_bytes())?;
+    assert_eq!("a", list.serialize_value_with(&options)?);
+    Ok(())
+}
+
+#[test]
+fn serialize_value_with_options_errors() -> Result<(), Box<dyn Error>> {
+    let options = SerializeOptions {
+        space_after_semicolon: true,
+        space_after_equals: true,
+        ..SerializeOptions::default()
+    };
+    assert_eq!(
+        Err("serialize_dictionary: serializing empty field is not allowed"),
+        Dictionary::default().serialize_value_with(&options)
+    );
+    let item = Item::with_params(
+        BareItem::Integer(1),
+        Parameters::from_iter(vec![("Key".to_owned(), BareItem::Integer(1))]),
+    );
+    assert_eq!(
+        Err("serialize_key: disallowed character in input"),
+        item.serialize_value_with(&options)
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_decimal_negative_zero() -> Result<(), Box<dyn Error>> {
+    let mut negative_zero = Decimal::new(0, 1);
+    negative_zero.set_sign_negative(true);
+    assert!(negative_zero.is_sign_negative());
+
+    let item = Item::new(BareItem::Decimal(negative_zero));
+    assert_eq!("0.0", item.serialize_value()?);
+    assert_eq!(3, item.serialized_len()?);
+
+    // Values rounded to zero lose their sign as well
+    let item = Item::new(BareItem::Decimal(Decimal::from_str("-0.0004")?));
+    assert_eq!("0.0", item.serialize_value()?);
+
+    let item = Item::new(BareItem::Decimal(Decimal::from_str("-0.0005")?));
+    assert_eq!("0.0", item.serialize_value()?);
+    let item = Item::new(BareItem::Decimal(Decimal::from_str("-0.0015")?));
+    assert_eq!("-0.002", item.serialize_value()?);
+
+    let parsed = Parser::parse_item("-0.0".as_bytes())?;
+    assert_eq!("0.0", parsed.serialize_value()?);
+    Ok(())
+}
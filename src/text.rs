@@ -0,0 +1,212 @@
+/*!
+Parsing of structured field values which type is known only at runtime,
+e.g. read from a configuration file or a test case description.
+
+# Examples
+```
+use sfv::text::{self, FieldType, FieldValue};
+use sfv::SerializeValue;
+
+let field_type: FieldType = "list".parse().unwrap();
+let value = text::parse_field(field_type, "a, (b c);d").unwrap();
+assert!(matches!(value, FieldValue::List(_)));
+assert_eq!(value.serialize_value().unwrap(), "a, (b c);d");
+```
+*/
+
+use crate::{
+    Dictionary, FromStr, Item, List, Parser, SFVResult, SerializeOptions, SerializeValue,
+    StructuredField,
+};
+use alloc::string::String;
+
+/// Type of structured field value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    /// Field of `Item` type.
+    Item,
+    /// Field of `List` type.
+    List,
+    /// Field of `Dictionary` type.
+    Dictionary,
+}
+
+impl FromStr for FieldType {
+    type Err = &'static str;
+
+    /// Parses field type from its name as used in RFC 8941, i.e. `item`, `list` or `dictionary`.
+    fn from_str(name: &str) -> SFVResult<FieldType> {
+        match name {
+            "item" => Ok(FieldType::Item),
+            "list" => Ok(FieldType::List),
+            "dictionary" => Ok(FieldType::Dictionary),
+            _ => Err("field_type: unknown field type"),
+        }
+    }
+}
+
+/// Structured field value of any type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    /// Value of `Item` type.
+    Item(Item),
+    /// Value of `List` type.
+    List(List),
+    /// Value of `Dictionary` type.
+    Dictionary(Dictionary),
+}
+
+impl FieldValue {
+    /// Returns type of the structured field value.
+    pub fn field_type(&self) -> FieldType {
+        match self {
+            FieldValue::Item(_) => FieldType::Item,
+            FieldValue::List(_) => FieldType::List,
+            FieldValue::Dictionary(_) => FieldType::Dictionary,
+        }
+    }
+}
+
+impl SerializeValue for FieldValue {
+    fn serialize_value(&self) -> SFVResult<String> {
+        match self {
+            FieldValue::Item(item) => item.serialize_value(),
+            FieldValue::List(list) => list.serialize_value(),
+            FieldValue::Dictionary(dict) => dict.serialize_value(),
+        }
+    }
+
+    fn serialize_value_or_empty(&self) -> SFVResult<Option<String>> {
+        match self {
+            FieldValue::Item(item) => item.serialize_value_or_empty(),
+            FieldValue::List(list) => list.serialize_value_or_empty(),
+            FieldValue::Dictionary(dict) => dict.serialize_value_or_empty(),
+        }
+    }
+
+    fn serialized_len(&self) -> SFVResult<usize> {
+        match self {
+            FieldValue::Item(item) => item.serialized_len(),
+            FieldValue::List(list) => list.serialized_len(),
+            FieldValue::Dictionary(dict) => dict.serialized_len(),
+        }
+    }
+
+    fn serialize_value_with(&self, options: &SerializeOptions) -> SFVResult<String> {
+        match self {
+            FieldValue::Item(item) => item.serialize_value_with(options),
+            FieldValue::List(list) => list.serialize_value_with(options),
+            FieldValue::Dictionary(dict) => dict.serialize_value_with(options),
+        }
+    }
+}
+
+impl StructuredField for FieldValue {
+    fn member_count(&self) -> usize {
+        match self {
+            FieldValue::Item(item) => item.member_count(),
+            FieldValue::List(list) => list.member_count(),
+            FieldValue::Dictionary(dict) => dict.member_count(),
+        }
+    }
+}
+
+/// Parses `input` into structured field value of type `field_type`.
+pub fn parse_field(field_type: FieldType, input: &str) -> SFVResult<FieldValue> {
+    let input_bytes = input.as_bytes();
+    match field_type {
+        FieldType::Item => Parser::parse_item(input_bytes).map(FieldValue::Item),
+        FieldType::List => Parser::parse_list(input_bytes).map(FieldValue::List),
+        FieldType::Dictionary => Parser::parse_dictionary(input_bytes).map(FieldValue::Dictionary),
+    }
+}
+
+#[cfg(test)]
+mod text_tests {
+    use super::*;
+    use crate::BareItem;
+    use std::error::Error;
+
+    #[test]
+    fn parse_field_of_each_type() -> Result<(), Box<dyn Error>> {
+        let value = parse_field(FieldType::Item, "1;a")?;
+        assert_eq!(FieldValue::Item(Parser::parse_item(b"1;a")?), value);
+        assert_eq!(FieldType::Item, value.field_type());
+        assert_eq!("1;a", value.serialize_value()?);
+
+        let value = parse_field(FieldType::List, "1, (2 3)")?;
+        assert_eq!(FieldValue::List(Parser::parse_list(b"1, (2 3)")?), value);
+        assert_eq!(FieldType::List, value.field_type());
+        assert_eq!("1, (2 3)", value.serialize_value()?);
+
+        let value = parse_field(FieldType::Dictionary, "a=1, b")?;
+        assert_eq!(
+            FieldValue::Dictionary(Parser::parse_dictionary(b"a=1, b")?),
+            value
+        );
+        assert_eq!(FieldType::Dictionary, value.field_type());
+        assert_eq!("a=1, b", value.serialize_value()?);
+
+        // Same input is interpreted according to the requested type
+        let value = parse_field(FieldType::Item, "tok")?;
+        assert_eq!(
+            FieldValue::Item(Item::new(BareItem::Token("tok".to_owned()))),
+            value
+        );
+        let value = parse_field(FieldType::Dictionary, "tok")?;
+        assert_eq!("tok", value.serialize_value()?);
+        assert_eq!(FieldType::Dictionary, value.field_type());
+
+        let value = parse_field(FieldType::List, "")?;
+        assert_eq!(None, value.serialize_value_or_empty()?);
+        assert!(StructuredField::is_empty(&value));
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_field_value_with_options() -> Result<(), Box<dyn Error>> {
+        let options = SerializeOptions {
+            space_after_semicolon: true,
+            ..SerializeOptions::default()
+        };
+        let inputs = [
+            (FieldType::Item, "1;a;b=2", "1; a; b=2"),
+            (FieldType::List, "1;a, (2 3);b", "1; a, (2 3); b"),
+            (FieldType::Dictionary, "x=1;a, y;b", "x=1; a, y; b"),
+        ];
+        for (field_type, input, expected) in inputs.iter() {
+            let value = parse_field(*field_type, input)?;
+            assert_eq!(*expected, value.serialize_value_with(&options)?);
+            assert_eq!(input.len(), value.serialized_len()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_field_errors() {
+        assert_eq!(
+            Err("parse: trailing characters after parsed value"),
+            parse_field(FieldType::Item, "1, 2")
+        );
+        assert_eq!(
+            Err("parse_bare_item: empty item"),
+            parse_field(FieldType::Item, "")
+        );
+        assert_eq!(
+            Err("parse_dict: trailing characters after dictionary member"),
+            parse_field(FieldType::Dictionary, "a=1 b")
+        );
+    }
+
+    #[test]
+    fn parse_field_type() -> Result<(), Box<dyn Error>> {
+        assert_eq!(FieldType::Item, "item".parse()?);
+        assert_eq!(FieldType::List, "list".parse()?);
+        assert_eq!(FieldType::Dictionary, "dictionary".parse()?);
+        assert_eq!(
+            Err("field_type: unknown field type"),
+            "Dictionary".parse::<FieldType>()
+        );
+        Ok(())
+    }
+}
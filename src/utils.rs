@@ -1,6 +1,7 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::iter::Peekable;
+use core::str::Chars;
 use data_encoding::{Encoding, Specification};
-use std::iter::Peekable;
-use std::str::Chars;
 
 pub(crate) fn base64() -> Result<Encoding, &'static str> {
     let mut spec = Specification::new();
@@ -23,7 +24,79 @@ pub(crate) fn is_allowed_b64_content(c: char) -> bool {
     c.is_ascii_alphanumeric() || c == '+' || c == '=' || c == '/'
 }
 
-pub(crate) fn consume_ows_chars(input_chars: &mut Peekable<Chars>) {
+/// Input of parsing functions, i.e. characters with a single character lookahead.
+pub(crate) trait Input: Iterator<Item = char> + Clone {
+    /// Returns the next character without consuming it.
+    fn peek(&mut self) -> Option<&char>;
+}
+
+impl Input for Peekable<Chars<'_>> {
+    fn peek(&mut self) -> Option<&char> {
+        Peekable::peek(self)
+    }
+}
+
+/// Same as `Peekable<Chars>`, but also tells the byte offset of the next character in the input,
+/// so that parsed values can be located in the input.
+#[derive(Clone)]
+pub(crate) struct OffsetChars<'a> {
+    input_len: usize,
+    chars: Chars<'a>,
+    peeked: Option<char>,
+}
+
+impl<'a> OffsetChars<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        OffsetChars {
+            input_len: input.len(),
+            chars: input.chars(),
+            peeked: None,
+        }
+    }
+
+    /// Returns the byte offset of the next character, or the input length at the end of the input.
+    pub(crate) fn offset(&self) -> usize {
+        let peeked_len = self.peeked.map_or(0, char::len_utf8);
+        self.input_len - self.chars.as_str().len() - peeked_len
+    }
+}
+
+impl Iterator for OffsetChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.peeked.take().or_else(|| self.chars.next())
+    }
+}
+
+impl Input for OffsetChars<'_> {
+    fn peek(&mut self) -> Option<&char> {
+        if self.peeked.is_none() {
+            self.peeked = self.chars.next();
+        }
+        self.peeked.as_ref()
+    }
+}
+
+/// Output of scanning functions, which either collects scanned characters or discards them.
+pub(crate) trait CharOutput {
+    fn push(&mut self, c: char);
+}
+
+impl CharOutput for String {
+    fn push(&mut self, c: char) {
+        String::push(self, c)
+    }
+}
+
+/// Discards scanned characters, so that input can be checked without allocating.
+pub(crate) struct Discard;
+
+impl CharOutput for Discard {
+    fn push(&mut self, _c: char) {}
+}
+
+pub(crate) fn consume_ows_chars(input_chars: &mut impl Input) {
     while let Some(c) = input_chars.peek() {
         if c == &' ' || c == &'\t' {
             input_chars.next();
@@ -33,7 +106,7 @@ pub(crate) fn consume_ows_chars(input_chars: &mut Peekable<Chars>) {
     }
 }
 
-pub(crate) fn consume_sp_chars(input_chars: &mut Peekable<Chars>) {
+pub(crate) fn consume_sp_chars(input_chars: &mut impl Input) {
     while let Some(c) = input_chars.peek() {
         if c == &' ' {
             input_chars.next();
@@ -42,3 +115,61 @@ pub(crate) fn consume_sp_chars(input_chars: &mut Peekable<Chars>) {
         }
     }
 }
+
+/// 64-bit FNV-1a hasher, used for `Dictionary` and `Parameters` when `std` feature is disabled.
+/// It is not keyed, so it offers no protection against hash flooding, see `HashBuilder`.
+#[cfg(not(feature = "std"))]
+pub struct FnvHasher(u64);
+
+#[cfg(not(feature = "std"))]
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::hash::Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Fixed-size buffer for characters of a number being parsed, so that parsing numbers doesn't allocate.
+/// Number parsing rejects integers longer than 15 and decimals longer than 16 characters
+/// right after the offending character is pushed, so at most 17 characters are ever stored.
+pub(crate) struct NumberBuffer {
+    chars: [u8; 17],
+    len: usize,
+}
+
+impl NumberBuffer {
+    pub(crate) fn new() -> Self {
+        NumberBuffer {
+            chars: [0; 17],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, c: char) {
+        debug_assert!(c.is_ascii_digit() || c == '.');
+        self.chars[self.len] = c as u8;
+        self.len += 1;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only ASCII digits and '.' are pushed, so conversion never fails
+        core::str::from_utf8(&self.chars[..self.len]).unwrap_or_default()
+    }
+}
@@ -0,0 +1,133 @@
+use sfv::{
+    Decimal, FromPrimitive, Parser, ParserConfig, RefBareItem, RefItemSerializer, SerializeValue,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::error::Error;
+
+// Counts new allocations made by the current thread.
+// Reallocations are not counted, so growing a single output buffer counts as one allocation.
+// Decimals and byte sequences are serialized by the crate itself rather than by `Display`
+// of `Decimal` and `encode_append` of `data-encoding`, so the counts don't depend on
+// versions or enabled features of those dependencies, and tests pass with `--all-features`.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let output = f();
+    (output, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn serialize_value_allocates_output_only() -> Result<(), Box<dyn Error>> {
+    let input = "\"str\\\"ing\";int=-42;dec=1.5;tok=*foo;bin=:aGVsbG8=:;bool=?0;flag";
+    let item = Parser::parse_item(input.as_bytes())?;
+
+    // Bare items are borrowed rather than cloned, so the output String is the only allocation
+    let (serialized, allocations) = count_allocations(|| item.serialize_value());
+    assert_eq!(input, serialized?);
+    assert_eq!(1, allocations);
+
+    let list = Parser::parse_list("(\"a\" tok :YQ==:);x=1, 2.5;y=\"b\"".as_bytes())?;
+    let (serialized, allocations) = count_allocations(|| list.serialize_value());
+    assert_eq!("(\"a\" tok :YQ==:);x=1, 2.5;y=\"b\"", serialized?);
+    assert_eq!(1, allocations);
+
+    // Byte sequence longer than the encoding buffer and decimals with each scale
+    let item =
+        Parser::parse_item(format!(":{}:;a=-0.25;b=12.5;c=3.125", "YWJj".repeat(40)).as_bytes())?;
+    let (serialized, allocations) = count_allocations(|| item.serialize_value());
+    assert_eq!(
+        format!(":{}:;a=-0.25;b=12.5;c=3.125", "YWJj".repeat(40)),
+        serialized?
+    );
+    assert_eq!(1, allocations);
+    Ok(())
+}
+
+#[test]
+fn ref_serializer_does_not_allocate() -> Result<(), Box<dyn Error>> {
+    let decimal = Decimal::from_f64(-1.5).ok_or("decimal conversion failed")?;
+    let mut output = String::with_capacity(128);
+
+    let (result, allocations) = count_allocations(|| {
+        RefItemSerializer::new(&mut output)
+            .bare_item(&RefBareItem::String("str"))?
+            .parameter("int", &RefBareItem::Integer(10))?
+            .parameter("dec", &RefBareItem::Decimal(decimal))?
+            .parameter("tok", &RefBareItem::Token("*foo"))?
+            .parameter("bin", &RefBareItem::ByteSeq("hello".as_bytes()))?
+            .parameter("bool", &RefBareItem::Boolean(true))
+            .map(|_| ())
+    });
+    result?;
+    assert_eq!(0, allocations);
+    assert_eq!(
+        "\"str\";int=10;dec=-1.5;tok=*foo;bin=:aGVsbG8=:;bool",
+        output
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_does_not_allocate() -> Result<(), Box<dyn Error>> {
+    let item = format!(
+        "\"str\\\"ing\";int=-42;dec=1.5;tok=*foo/bar;bin=:{}:;bool=?0;flag",
+        "YWJj".repeat(40)
+    );
+    let list = "(\"a\" tok :YQ==:);x=1, 2.5;y=\"b\", (), ?1";
+    let dictionary = "a=1, b=(1 2);x=:YWI:, c, d=\"long string with \\\\ escapes\";e=tok";
+
+    let (result, allocations) = count_allocations(|| Parser::validate_item(item.as_bytes()));
+    result?;
+    assert_eq!(0, allocations);
+
+    let (result, allocations) = count_allocations(|| Parser::validate_list(list.as_bytes()));
+    result?;
+    assert_eq!(0, allocations);
+
+    let (result, allocations) =
+        count_allocations(|| Parser::validate_dictionary(dictionary.as_bytes()));
+    result?;
+    assert_eq!(0, allocations);
+
+    // Invalid input is rejected without allocating either
+    let (result, allocations) = count_allocations(|| Parser::validate_list(b"(1 2), :YWJjZ:"));
+    assert_eq!(Err("parse_byte_seq: decoding error"), result);
+    assert_eq!(0, allocations);
+
+    let config = ParserConfig {
+        strict_numbers: true,
+        lenient_whitespace: true,
+        allow_trailing_comma: true,
+        max_byte_seq_len: Some(1024),
+        ..ParserConfig::default()
+    };
+    let (result, allocations) =
+        count_allocations(|| config.validate_dictionary("a = 1 ;x, b=:YWI:,".as_bytes()));
+    result?;
+    assert_eq!(0, allocations);
+    Ok(())
+}
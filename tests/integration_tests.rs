@@ -1,4 +1,8 @@
-use sfv::{ListEntry, Parser};
+use sfv::{
+    BareItem, Decimal, DictionaryExt, FromStr, InnerList, Item, ListEntry, ListExt, Parameters,
+    ParametersExt, Parser, SerializeValue, StructuredField, MAX_INTEGER, MIN_INTEGER,
+};
+use std::collections::HashSet;
 use std::error::Error;
 
 #[test]
@@ -32,3 +36,465 @@ fn test_report_to_header() -> Result<(), Box<dyn Error>> {
     }
     Err("unexpected endpoint value".into())
 }
+
+#[test]
+fn test_values_as_set_members() -> Result<(), Box<dyn Error>> {
+    fn assert_eq_and_hash<T: Eq + std::hash::Hash>() {}
+    assert_eq_and_hash::<BareItem>();
+    assert_eq_and_hash::<Item>();
+    assert_eq_and_hash::<InnerList>();
+    assert_eq_and_hash::<ListEntry>();
+
+    let list = Parser::parse_list(b"1;a;b, 1;b;a, (1 2);q=0.5, (1 2);q=0.50, 1.5, 1.50")?;
+    let members: HashSet<&ListEntry> = list.iter().collect();
+    assert_eq!(members.len(), 3);
+
+    let bare_items: HashSet<BareItem> = list
+        .iter()
+        .filter_map(|member| member.as_item())
+        .map(|item| item.bare_item.clone())
+        .collect();
+    assert_eq!(bare_items.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_compact_parsed_values() -> Result<(), Box<dyn Error>> {
+    let members: Vec<String> = (0..100)
+        .map(|i| format!("({} {} {});a={}", i, i, i, i))
+        .collect();
+    let mut list = Parser::parse_list(members.join(", ").as_bytes())?;
+    assert!(list.capacity() > list.len());
+
+    list.compact();
+    assert_eq!(list.capacity(), list.len());
+    for member in list.iter() {
+        let inner_list = member
+            .as_inner_list()
+            .ok_or("unexpected ListEntry variant")?;
+        assert_eq!(inner_list.items.capacity(), inner_list.items.len());
+        assert_eq!(inner_list.params.capacity(), inner_list.params.len());
+    }
+    assert_eq!(Parser::parse_list(members.join(", ").as_bytes())?, list);
+
+    let members: Vec<String> = (0..100).map(|i| format!("k{}=(1 2 3 4 5)", i)).collect();
+    let mut dict = Parser::parse_dictionary(members.join(", ").as_bytes())?;
+    assert!(dict.capacity() > dict.len());
+
+    dict.compact();
+    assert_eq!(dict.capacity(), dict.len());
+    let inner_list = dict
+        .get_inner_list("k0")
+        .ok_or("unexpected ListEntry variant")?;
+    assert_eq!(inner_list.items.capacity(), inner_list.items.len());
+    Ok(())
+}
+
+#[test]
+fn test_strip_internal_parameters() -> Result<(), Box<dyn Error>> {
+    let mut dict = Parser::parse_dictionary(b"a=1;x-trace=2;b, x-debug, c=(1;x-a 2);x-b;z")?;
+    dict.retain_entries(|key, _| !key.starts_with("x-"));
+    for member in dict.values_mut() {
+        match member {
+            ListEntry::Item(item) => item.params.retain_entries(|key, _| !key.starts_with("x-")),
+            ListEntry::InnerList(inner_list) => {
+                inner_list
+                    .params
+                    .retain_entries(|key, _| !key.starts_with("x-"));
+                for item in inner_list.items.iter_mut() {
+                    item.params.retain_entries(|key, _| !key.starts_with("x-"));
+                }
+            }
+        }
+    }
+    assert_eq!("a=1;b, c=(1 2);z", dict.serialize_value()?);
+
+    let mut dict = Parser::parse_dictionary(b"a=1, b=2, c=(3)")?;
+    dict.retain_entries(|_, member| {
+        member.as_item().and_then(|item| item.bare_item.as_int()) == Some(2)
+    });
+    assert_eq!("b=2", dict.serialize_value()?);
+    Ok(())
+}
+
+#[test]
+fn test_upsert_dictionary_member_params() -> Result<(), Box<dyn Error>> {
+    let mut dict = Parser::parse_dictionary(b"a=1;x=2, b=(1 2)")?;
+
+    dict.upsert_param("a", "x", BareItem::Integer(3))?;
+    dict.upsert_param("a", "y", BareItem::Boolean(true))?;
+    dict.upsert_param("b", "z", BareItem::String("str".to_owned()))?;
+    dict.upsert_param("c", "w", BareItem::Boolean(false))?;
+    assert_eq!(
+        "a=1;x=3;y, b=(1 2);z=\"str\", c;w=?0",
+        dict.serialize_value()?
+    );
+
+    assert_eq!(
+        Err("serialize_key: disallowed character in input"),
+        dict.upsert_param("New", "x", BareItem::Integer(1))
+    );
+    assert_eq!(
+        Err("serialize_key: first character is not lcalpha or '*'"),
+        dict.upsert_param("d", "1x", BareItem::Integer(1))
+    );
+    assert_eq!(
+        Err("serialize_integer: integer is out of range"),
+        dict.upsert_param("d", "x", BareItem::Integer(1_000_000_000_000_000))
+    );
+    // Nothing is inserted on error
+    assert!(!dict.contains_key("d"));
+    Ok(())
+}
+
+#[test]
+fn test_params_iter_document_order() -> Result<(), Box<dyn Error>> {
+    let list = Parser::parse_list(b"a;p1=1;p2, (b;p3=x c d;p4);p5=?0, (), e, ();p6")?;
+    let params: Vec<(&str, &BareItem)> = list.params_iter().collect();
+    assert_eq!(
+        vec![
+            ("p1", &BareItem::Integer(1)),
+            ("p2", &BareItem::Boolean(true)),
+            ("p3", &BareItem::Token("x".to_owned())),
+            ("p4", &BareItem::Boolean(true)),
+            ("p5", &BareItem::Boolean(false)),
+            ("p6", &BareItem::Boolean(true)),
+        ],
+        params
+    );
+
+    let dict = Parser::parse_dictionary(b"a;p1, b=(1;p2);p3, c=2")?;
+    let keys: Vec<&str> = dict.params_iter().map(|(key, _)| key).collect();
+    assert_eq!(vec!["p1", "p2", "p3"], keys);
+    Ok(())
+}
+
+#[test]
+fn test_integer_bounds() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        BareItem::Integer(MAX_INTEGER),
+        BareItem::new_integer_checked(MAX_INTEGER)?
+    );
+    assert_eq!(
+        BareItem::Integer(MIN_INTEGER),
+        BareItem::new_integer_checked(MIN_INTEGER)?
+    );
+    assert_eq!(BareItem::Integer(7), BareItem::new_integer_checked(7_u8)?);
+    assert_eq!(
+        BareItem::Integer(1_700_000_000_000),
+        BareItem::new_integer_checked(1_700_000_000_000_u64)?
+    );
+
+    let error = Err("new_integer_checked: integer is out of range");
+    assert_eq!(error, BareItem::new_integer_checked(MAX_INTEGER + 1));
+    assert_eq!(error, BareItem::new_integer_checked(MIN_INTEGER - 1));
+    assert_eq!(error, BareItem::new_integer_checked(u64::MAX));
+    assert_eq!(error, BareItem::new_integer_checked(i128::MIN));
+
+    // Bounds match the ones of parsing and serialization
+    let item = Item::new(BareItem::Integer(MAX_INTEGER));
+    assert_eq!(
+        item,
+        Parser::parse_item(item.serialize_value()?.as_bytes())?
+    );
+    assert!(Item::new(BareItem::Integer(MIN_INTEGER - 1))
+        .serialize_value()
+        .is_err());
+    assert!(Parser::parse_item((MAX_INTEGER + 1).to_string().as_bytes()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_exact() -> Result<(), Box<dyn Error>> {
+    for input in ["13.37", "-0.001", "999999999999.999", "1.5000", "42"].iter() {
+        let value = Decimal::from_str(input)?;
+        assert_eq!(
+            BareItem::Decimal(value),
+            BareItem::new_decimal_exact(value)?
+        );
+    }
+
+    let error = Err("new_decimal_exact: more than three fractional digits");
+    assert_eq!(
+        error,
+        BareItem::new_decimal_exact(Decimal::from_str("0.0005")?)
+    );
+    assert_eq!(
+        error,
+        BareItem::new_decimal_exact(Decimal::from_str("-12.3456")?)
+    );
+    assert_eq!(
+        Err("new_decimal_exact: decimal is out of range"),
+        BareItem::new_decimal_exact(Decimal::from_str("1000000000000")?)
+    );
+
+    // Rounding constructor accepts the same value
+    assert_eq!(
+        BareItem::Decimal(Decimal::from_str("-12.346")?),
+        BareItem::new_decimal_from_parts(-123456, 4)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal_from_parts() -> Result<(), Box<dyn Error>> {
+    let bare_item = BareItem::new_decimal_from_parts(1337, 2)?;
+    assert_eq!(BareItem::Decimal(Decimal::from_str("13.37")?), bare_item);
+    assert_eq!("13.37", Item::new(bare_item).serialize_value()?);
+
+    assert_eq!(
+        BareItem::Decimal(Decimal::from_str("-0.5")?),
+        BareItem::new_decimal_from_parts(-5, 1)?
+    );
+    assert_eq!(
+        BareItem::Decimal(Decimal::from(42)),
+        BareItem::new_decimal_from_parts(42, 0)?
+    );
+
+    // Fractional part is rounded half to even to three digits
+    assert_eq!(
+        BareItem::Decimal(Decimal::from_str("1.234")?),
+        BareItem::new_decimal_from_parts(12345, 4)?
+    );
+    assert_eq!(
+        BareItem::Decimal(Decimal::from_str("1.236")?),
+        BareItem::new_decimal_from_parts(12355, 4)?
+    );
+
+    let max_decimal = BareItem::new_decimal_from_parts(999_999_999_999_999, 3)?;
+    assert_eq!(
+        "999999999999.999",
+        Item::new(max_decimal).serialize_value()?
+    );
+    assert_eq!(
+        Err("new_decimal_from_parts: decimal is out of range"),
+        BareItem::new_decimal_from_parts(1_000_000_000_000_000, 3)
+    );
+    assert_eq!(
+        Err("new_decimal_from_parts: scale is out of range"),
+        BareItem::new_decimal_from_parts(1, 29)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_structured_field_generic_over_types() -> Result<(), Box<dyn Error>> {
+    fn field_size(field: &impl StructuredField) -> Result<(usize, usize), &'static str> {
+        if field.is_empty() {
+            return Ok((0, 0));
+        }
+        Ok((field.member_count(), field.serialized_len()?))
+    }
+
+    assert_eq!((1, 5), field_size(&Parser::parse_item(b"tok;a")?)?);
+    assert_eq!((3, 13), field_size(&Parser::parse_list(b"1, (2 3), 4;x")?)?);
+    assert_eq!(
+        (2, 9),
+        field_size(&Parser::parse_dictionary(b"a=1, b=?0")?)?
+    );
+    assert_eq!((0, 0), field_size(&Parser::parse_list(b"")?)?);
+    assert_eq!((0, 0), field_size(&Parser::parse_dictionary(b"")?)?);
+    Ok(())
+}
+
+sfv::token_enum! {
+    enum Directive {
+        NoCache => "no-cache",
+        Private => "private",
+    }
+}
+
+#[test]
+fn test_token_enum() -> Result<(), Box<dyn Error>> {
+    use std::convert::TryFrom;
+
+    let list = Parser::parse_list(b"no-cache, private, max-age, \"private\"")?;
+    let directives: Vec<_> = list
+        .iter()
+        .filter_map(|member| member.as_item())
+        .map(|item| Directive::try_from(&item.bare_item))
+        .collect();
+    assert_eq!(
+        vec![
+            Ok(Directive::NoCache),
+            Ok(Directive::Private),
+            Ok(Directive::Unknown("max-age".to_owned())),
+            Err("token_enum: bare item is not a token"),
+        ],
+        directives
+    );
+
+    assert_eq!("no-cache", Directive::NoCache.as_str());
+    assert_eq!("Private", Directive::Unknown("Private".to_owned()).as_str());
+    assert_eq!(
+        Directive::Unknown("Private".to_owned()),
+        "Private".parse::<Directive>()?
+    );
+    assert_eq!(
+        BareItem::Token("private".to_owned()),
+        BareItem::from(Directive::Private)
+    );
+    assert_eq!(
+        Err("serialise_token: first character is not ALPHA or '*'"),
+        "1st".parse::<Directive>()
+    );
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        Directive::try_from(&BareItem::Token(String::new()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extend_params() -> Result<(), Box<dyn Error>> {
+    let mut inner_list = InnerList::new(vec![Item::new(1.into()), Item::new(2.into())]);
+    inner_list
+        .extend_params(vec![
+            ("a".to_owned(), BareItem::Token("tok".to_owned())),
+            ("b".to_owned(), BareItem::Boolean(true)),
+        ])
+        .map_err(|(_, err)| err)?;
+    assert_eq!("(1 2);a=tok;b", inner_list.to_string());
+
+    // Existing parameters are overwritten in place
+    inner_list
+        .extend_params(vec![("a".to_owned(), 5.into())])
+        .map_err(|(_, err)| err)?;
+    assert_eq!("(1 2);a=5;b", inner_list.to_string());
+
+    // Invalid parameter is reported with its key and nothing is inserted
+    assert_eq!(
+        Err(("".to_owned(), "serialize_key: empty key")),
+        inner_list.extend_params(vec![
+            ("c".to_owned(), 3.into()),
+            ("".to_owned(), 4.into()),
+            ("d".to_owned(), 5.into()),
+        ])
+    );
+    assert_eq!(
+        Err(("e".to_owned(), "serialize_string: non-ascii character")),
+        inner_list.extend_params(vec![("e".to_owned(), BareItem::String("é".to_owned()))])
+    );
+    assert_eq!("(1 2);a=5;b", inner_list.to_string());
+    Ok(())
+}
+
+#[test]
+fn test_value_macros() -> Result<(), Box<dyn Error>> {
+    let item = sfv::sfv_item!(
+        BareItem::Token("text/html".to_owned());
+        "charset" => BareItem::Token("utf-8".to_owned()),
+        "q" => Decimal::from_str("0.5")?
+    )?;
+    assert_eq!(Parser::parse_item(b"text/html;charset=utf-8;q=0.5")?, item);
+    assert_eq!(Item::new(BareItem::Integer(1)), sfv::sfv_item!(1)?);
+
+    // Keys may be computed
+    let key = format!("k{}", 1);
+    let item = sfv::sfv_item!(true; key.clone() => 1, "b" => true,)?;
+    assert_eq!("?1;k1=1;b", item.serialize_value()?);
+
+    let list = sfv::sfv_list![
+        sfv::sfv_item!(1; "a" => 2)?,
+        InnerList::new(vec![sfv::sfv_item!(b"hello".to_vec())?]),
+    ]?;
+    assert_eq!("1;a=2, (:aGVsbG8=:)", list.serialize_value()?);
+    assert!(sfv::sfv_list![]?.is_empty());
+
+    let dict = sfv::sfv_dict! {
+        "a" => sfv::sfv_item!(1)?,
+        key => sfv::sfv_item!(true)?,
+    }?;
+    assert_eq!("a=1, k1", dict.serialize_value()?);
+    Ok(())
+}
+
+#[test]
+fn test_value_macros_errors() {
+    assert_eq!(
+        Err("serialize_key: disallowed character in input"),
+        sfv::sfv_item!(1; "a" => 1, "B" => 2)
+    );
+    assert_eq!(
+        Err("serialise_token: empty token"),
+        sfv::sfv_item!(BareItem::Token(String::new()))
+    );
+    assert_eq!(
+        Err("serialize_integer: integer is out of range"),
+        sfv::sfv_list![Item::new(BareItem::Integer(MAX_INTEGER + 1))]
+    );
+    assert_eq!(
+        Err("serialize_key: empty key"),
+        sfv::sfv_list![InnerList::with_params(
+            vec![],
+            vec![(String::new(), BareItem::Boolean(true))]
+                .into_iter()
+                .collect()
+        )]
+    );
+    assert_eq!(
+        Err("serialize_key: disallowed character in input"),
+        sfv::sfv_dict! { "a b" => Item::new(1.into()) }
+    );
+    assert_eq!(
+        Err("sfv_item: duplicate parameter key"),
+        sfv::sfv_item!(1; "a" => 1, "b" => 2, "a" => 3)
+    );
+    assert_eq!(
+        Err("sfv_dict: duplicate key"),
+        sfv::sfv_dict! { "a" => Item::new(1.into()), "a" => Item::new(2.into()) }
+    );
+}
+
+#[test]
+fn test_parameters_try_from_pairs() -> Result<(), Box<dyn Error>> {
+    let params = Parameters::try_from_pairs(vec![
+        ("a".to_owned(), BareItem::Integer(1)),
+        ("b".to_owned(), BareItem::Token("tok".to_owned())),
+        ("c".to_owned(), BareItem::Boolean(true)),
+    ])
+    .map_err(|(_, err)| err)?;
+    let item = Item::with_params(BareItem::Integer(0), params);
+    assert_eq!("0;a=1;b=tok;c", item.serialize_value()?);
+
+    assert_eq!(
+        Ok(Parameters::default()),
+        Parameters::try_from_pairs(Vec::<(&str, BareItem)>::new())
+    );
+
+    // Errors name the key of the offending pair
+    assert_eq!(
+        Err(("a".to_owned(), "try_from_pairs: duplicate key")),
+        Parameters::try_from_pairs(vec![("a", true), ("b", false), ("a", true)])
+    );
+    assert_eq!(
+        Err((
+            "B".to_owned(),
+            "serialize_key: disallowed character in input"
+        )),
+        Parameters::try_from_pairs(vec![("a", 1), ("B", 2)])
+    );
+    assert_eq!(
+        Err(("a".to_owned(), "serialize_integer: integer is out of range")),
+        Parameters::try_from_pairs(vec![("a", MAX_INTEGER + 1)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strip_params() -> Result<(), Box<dyn Error>> {
+    let mut list =
+        Parser::parse_list("tok;a=1;b, (1;c 2;d=?0 \"s\");e=\"s\";f, ();g, :aGk=:;h".as_bytes())?;
+    list.strip_params();
+    assert!(list.params_iter().next().is_none());
+    assert_eq!(
+        Parser::parse_list("tok, (1 2 \"s\"), (), :aGk=:".as_bytes())?,
+        list
+    );
+
+    let mut dict = Parser::parse_dictionary("a=(1;x 2);y;z=3, b;w, c=?0;v".as_bytes())?;
+    dict.strip_params();
+    assert!(dict.params_iter().next().is_none());
+    assert_eq!("a=(1 2), b, c=?0", dict.serialize_value()?);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use sfv::{
+    BareItem, Decimal, Dictionary, InnerList, Item, List, ListEntry, Parameters, Parser,
+    SerializeValue, MAX_INTEGER, MIN_INTEGER,
+};
+
+// Strategies below only generate values that are valid for serialization,
+// i.e. satisfy the ranges and grammars defined in RFC 8941.
+
+fn integer() -> impl Strategy<Value = i64> {
+    MIN_INTEGER..=MAX_INTEGER
+}
+
+fn decimal() -> impl Strategy<Value = Decimal> {
+    // sf-decimal  = ["-"] 1*12DIGIT "." 1*3DIGIT
+    (0_u32..=3).prop_flat_map(|scale| {
+        let max_mantissa = 10_i64.pow(12 + scale) - 1;
+        (-max_mantissa..=max_mantissa).prop_map(move |mantissa| Decimal::new(mantissa, scale))
+    })
+}
+
+fn string() -> impl Strategy<Value = String> {
+    "[\\x20-\\x7e]{0,20}"
+}
+
+fn token() -> impl Strategy<Value = String> {
+    "[A-Za-z*][!#$%&'*+\\-.^_`|~0-9A-Za-z:/]{0,15}"
+}
+
+fn key() -> impl Strategy<Value = String> {
+    "[a-z*][a-z0-9_\\-.*]{0,10}"
+}
+
+fn bare_item() -> impl Strategy<Value = BareItem> {
+    prop_oneof![
+        integer().prop_map(BareItem::Integer),
+        decimal().prop_map(BareItem::Decimal),
+        string().prop_map(BareItem::String),
+        token().prop_map(BareItem::Token),
+        vec(any::<u8>(), 0..20).prop_map(BareItem::ByteSeq),
+        any::<bool>().prop_map(BareItem::Boolean),
+    ]
+}
+
+fn parameters() -> impl Strategy<Value = Parameters> {
+    vec((key(), bare_item()), 0..4).prop_map(|params| params.into_iter().collect())
+}
+
+fn item() -> impl Strategy<Value = Item> {
+    (bare_item(), parameters()).prop_map(|(bare_item, params)| Item::with_params(bare_item, params))
+}
+
+fn inner_list() -> impl Strategy<Value = InnerList> {
+    (vec(item(), 0..4), parameters())
+        .prop_map(|(items, params)| InnerList::with_params(items, params))
+}
+
+fn list_entry() -> impl Strategy<Value = ListEntry> {
+    prop_oneof![
+        item().prop_map(ListEntry::from),
+        inner_list().prop_map(ListEntry::from)
+    ]
+}
+
+fn list() -> impl Strategy<Value = List> {
+    // Serializing empty List is not allowed
+    vec(list_entry(), 1..5)
+}
+
+fn dictionary() -> impl Strategy<Value = Dictionary> {
+    // Serializing empty Dictionary is not allowed
+    vec((key(), list_entry()), 1..5).prop_map(|members| members.into_iter().collect())
+}
+
+proptest! {
+    #[test]
+    fn roundtrip_item(item in item()) {
+        let serialized = item.serialize_value().unwrap();
+        prop_assert_eq!(item, Parser::parse_item(serialized.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn roundtrip_list(list in list()) {
+        let serialized = list.serialize_value().unwrap();
+        prop_assert_eq!(list, Parser::parse_list(serialized.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn roundtrip_dictionary(dict in dictionary()) {
+        let serialized = dict.serialize_value().unwrap();
+        prop_assert_eq!(dict, Parser::parse_dictionary(serialized.as_bytes()).unwrap());
+    }
+
+    // Decimals are serialized without trailing zeros whatever their scale,
+    // so the output is accepted by strict parsing which rejects non-canonical numbers
+    #[test]
+    fn canonical_decimal(decimal in decimal()) {
+        let item = Item::new(BareItem::Decimal(decimal));
+        let serialized = item.serialize_value().unwrap();
+        prop_assert_eq!(item, Parser::strict().parse_item(serialized.as_bytes()).unwrap());
+    }
+
+    // Strings made mostly of characters that need escaping, to cover escapes at the boundaries
+    #[test]
+    fn roundtrip_escaped_string(string in "[\\\\\"a ]{0,20}") {
+        let bare_item = BareItem::String(string);
+        let serialized = Item::new(bare_item.clone()).serialize_value().unwrap();
+        prop_assert_eq!(bare_item, Parser::parse_bare_item(serialized.as_bytes()).unwrap());
+    }
+}
@@ -46,11 +46,9 @@ fn run_test_case(test_case: &TestData) -> Result<(), Box<dyn Error>> {
         .join(", ");
 
     let actual_result = match test_case.header_type.as_str() {
-        "item" => Parser::parse_item(input.as_bytes()).map(|itm| FieldType::Item(itm)),
-        "list" => Parser::parse_list(input.as_bytes()).map(|lst| FieldType::List(lst)),
-        "dictionary" => {
-            Parser::parse_dictionary(input.as_bytes()).map(|dict| FieldType::Dict(dict))
-        }
+        "item" => Parser::parse_item(input.as_bytes()).map(FieldType::Item),
+        "list" => Parser::parse_list(input.as_bytes()).map(FieldType::List),
+        "dictionary" => Parser::parse_dictionary(input.as_bytes()).map(FieldType::Dict),
         _ => return Err("run_test_case: unexpected field value type in test case".into()),
     };
 
@@ -129,7 +127,7 @@ fn build_expected_field_value(test_case: &TestData) -> Result<FieldType, Box<dyn
             let dict = build_dict(expected_value)?;
             Ok(FieldType::Dict(dict))
         }
-        _ => return Err("unknown field type".into()),
+        _ => Err("unknown field type".into()),
     }
 }
 
@@ -249,7 +247,6 @@ fn build_bare_item(bare_item_value: &Value) -> Result<BareItem, Box<dyn Error>>
             bare_item
                 .as_str()
                 .ok_or("build_bare_item: bare_item value is not a str")?
-                .clone()
                 .to_owned(),
         )),
         bare_item if (bare_item.is_object() && bare_item["__type"] == "token") => {
@@ -257,15 +254,13 @@ fn build_bare_item(bare_item_value: &Value) -> Result<BareItem, Box<dyn Error>>
                 bare_item["value"]
                     .as_str()
                     .ok_or("build_bare_item: bare_item value is not a str")?
-                    .clone()
                     .to_owned(),
             ))
         }
         bare_item if (bare_item.is_object() && bare_item["__type"] == "binary") => {
             let str_val = bare_item["value"]
                 .as_str()
-                .ok_or("build_bare_item: bare_item value is not a str")?
-                .clone();
+                .ok_or("build_bare_item: bare_item value is not a str")?;
             Ok(BareItem::ByteSeq(BASE32.decode(str_val.as_bytes())?))
         }
         _ => Err("build_bare_item: unknown bare_item value".into()),